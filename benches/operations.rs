@@ -0,0 +1,503 @@
+#![deny(clippy::all)]
+#![deny(clippy::pedantic)]
+#![deny(clippy::nursery)]
+
+//! Std-only benchmarks comparing `LinkedList` against the std collections.
+//!
+//! Run with `cargo bench --bench operations -- [--csv] [--max-size N]`.
+//! Results are reported in nanoseconds per element so that sizes can be
+//! compared directly.
+
+use std::{
+    collections::{self, VecDeque},
+    env,
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+use linked_list::LinkedList;
+
+const SIZES: [usize; 7] = [10, 100, 1_000, 10_000, 100_000, 1_000_000, 10_000_000];
+
+/// Every measurement processes at least this many elements in total, so that
+/// small sizes are repeated often enough to be timed reliably.
+const MIN_ELEMENTS_PER_MEASUREMENT: usize = 1_000_000;
+
+/// Front operations on `Vec` are quadratic, so they stop at this size.
+const MAX_VEC_FRONT_SIZE: usize = 100_000;
+
+trait Container: Sized {
+    const NAME: &'static str;
+
+    fn empty() -> Self;
+
+    fn filled(size: usize) -> Self {
+        let mut container = Self::empty();
+
+        for element in 0..size {
+            container.push_back(element);
+        }
+
+        container
+    }
+
+    fn push_front(&mut self, element: usize);
+    fn push_back(&mut self, element: usize);
+    fn pop_front(&mut self) -> Option<usize>;
+    fn pop_back(&mut self) -> Option<usize>;
+    fn sum(&self) -> usize;
+    fn retain_even(&mut self);
+    fn reverse(&mut self);
+    fn split(self) -> (Self, Self);
+    fn contains(&self, element: usize) -> bool;
+
+    fn supports_front(size: usize) -> bool {
+        let _ = size;
+
+        true
+    }
+}
+
+impl Container for LinkedList<usize> {
+    const NAME: &'static str = "linked_list::LinkedList";
+
+    fn empty() -> Self {
+        Self::new()
+    }
+
+    fn push_front(&mut self, element: usize) {
+        self.push_front(element);
+    }
+
+    fn push_back(&mut self, element: usize) {
+        self.push_back(element);
+    }
+
+    fn pop_front(&mut self) -> Option<usize> {
+        self.pop_front()
+    }
+
+    fn pop_back(&mut self) -> Option<usize> {
+        self.pop_back()
+    }
+
+    fn sum(&self) -> usize {
+        self.iter().sum()
+    }
+
+    fn retain_even(&mut self) {
+        self.retain(|element| element % 2 == 0);
+    }
+
+    fn reverse(&mut self) {
+        self.reverse();
+    }
+
+    fn split(self) -> (Self, Self) {
+        self.split()
+    }
+
+    fn contains(&self, element: usize) -> bool {
+        self.contains(&element)
+    }
+}
+
+impl Container for collections::LinkedList<usize> {
+    const NAME: &'static str = "std::collections::LinkedList";
+
+    fn empty() -> Self {
+        Self::new()
+    }
+
+    fn push_front(&mut self, element: usize) {
+        self.push_front(element);
+    }
+
+    fn push_back(&mut self, element: usize) {
+        self.push_back(element);
+    }
+
+    fn pop_front(&mut self) -> Option<usize> {
+        self.pop_front()
+    }
+
+    fn pop_back(&mut self) -> Option<usize> {
+        self.pop_back()
+    }
+
+    fn sum(&self) -> usize {
+        self.iter().sum()
+    }
+
+    fn retain_even(&mut self) {
+        // `LinkedList::retain` is unstable, so rebuild the list instead.
+        *self = std::mem::take(self)
+            .into_iter()
+            .filter(|element| element % 2 == 0)
+            .collect();
+    }
+
+    fn reverse(&mut self) {
+        let mut reversed = Self::new();
+
+        while let Some(element) = self.pop_front() {
+            reversed.push_front(element);
+        }
+
+        *self = reversed;
+    }
+
+    fn split(mut self) -> (Self, Self) {
+        let second = self.split_off(self.len().div_ceil(2));
+
+        (self, second)
+    }
+
+    fn contains(&self, element: usize) -> bool {
+        self.contains(&element)
+    }
+}
+
+impl Container for VecDeque<usize> {
+    const NAME: &'static str = "std::collections::VecDeque";
+
+    fn empty() -> Self {
+        Self::new()
+    }
+
+    fn push_front(&mut self, element: usize) {
+        self.push_front(element);
+    }
+
+    fn push_back(&mut self, element: usize) {
+        self.push_back(element);
+    }
+
+    fn pop_front(&mut self) -> Option<usize> {
+        self.pop_front()
+    }
+
+    fn pop_back(&mut self) -> Option<usize> {
+        self.pop_back()
+    }
+
+    fn sum(&self) -> usize {
+        self.iter().sum()
+    }
+
+    fn retain_even(&mut self) {
+        self.retain(|element| element % 2 == 0);
+    }
+
+    fn reverse(&mut self) {
+        self.make_contiguous().reverse();
+    }
+
+    fn split(mut self) -> (Self, Self) {
+        let second = self.split_off(self.len().div_ceil(2));
+
+        (self, second)
+    }
+
+    fn contains(&self, element: usize) -> bool {
+        self.contains(&element)
+    }
+}
+
+impl Container for Vec<usize> {
+    const NAME: &'static str = "std::vec::Vec";
+
+    fn empty() -> Self {
+        Self::new()
+    }
+
+    fn push_front(&mut self, element: usize) {
+        self.insert(0, element);
+    }
+
+    fn push_back(&mut self, element: usize) {
+        self.push(element);
+    }
+
+    fn pop_front(&mut self) -> Option<usize> {
+        if self.is_empty() {
+            None
+        } else {
+            Some(self.remove(0))
+        }
+    }
+
+    fn pop_back(&mut self) -> Option<usize> {
+        self.pop()
+    }
+
+    fn sum(&self) -> usize {
+        self.iter().sum()
+    }
+
+    fn retain_even(&mut self) {
+        self.retain(|element| element % 2 == 0);
+    }
+
+    fn reverse(&mut self) {
+        self.as_mut_slice().reverse();
+    }
+
+    fn split(mut self) -> (Self, Self) {
+        let second = self.split_off(self.len().div_ceil(2));
+
+        (self, second)
+    }
+
+    fn contains(&self, element: usize) -> bool {
+        self.as_slice().contains(&element)
+    }
+
+    fn supports_front(size: usize) -> bool {
+        size <= MAX_VEC_FRONT_SIZE
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Operation {
+    PushFront,
+    PushBack,
+    PopFront,
+    PopBack,
+    Iterate,
+    Retain,
+    Reverse,
+    Split,
+    Contains,
+    Drop,
+}
+
+impl Operation {
+    const ALL: [Self; 10] = [
+        Self::PushFront,
+        Self::PushBack,
+        Self::PopFront,
+        Self::PopBack,
+        Self::Iterate,
+        Self::Retain,
+        Self::Reverse,
+        Self::Split,
+        Self::Contains,
+        Self::Drop,
+    ];
+
+    const fn name(self) -> &'static str {
+        match self {
+            Self::PushFront => "push_front",
+            Self::PushBack => "push_back",
+            Self::PopFront => "pop_front",
+            Self::PopBack => "pop_back",
+            Self::Iterate => "iter",
+            Self::Retain => "retain",
+            Self::Reverse => "reverse",
+            Self::Split => "split",
+            Self::Contains => "contains",
+            Self::Drop => "drop",
+        }
+    }
+
+    const fn uses_front(self) -> bool {
+        matches!(self, Self::PushFront | Self::PopFront)
+    }
+}
+
+/// Times a single run of `operation` on a container of `size` elements,
+/// excluding the time needed to build the container.
+fn run_once<C: Container>(operation: Operation, size: usize) -> Duration {
+    match operation {
+        Operation::PushFront | Operation::PushBack => {
+            let mut container = C::empty();
+            let start = Instant::now();
+
+            for element in 0..size {
+                if matches!(operation, Operation::PushFront) {
+                    container.push_front(black_box(element));
+                } else {
+                    container.push_back(black_box(element));
+                }
+            }
+
+            let elapsed = start.elapsed();
+
+            black_box(&container);
+
+            elapsed
+        }
+        Operation::PopFront | Operation::PopBack => {
+            let mut container = C::filled(size);
+            let start = Instant::now();
+
+            if matches!(operation, Operation::PopFront) {
+                while black_box(container.pop_front()).is_some() {}
+            } else {
+                while black_box(container.pop_back()).is_some() {}
+            }
+
+            start.elapsed()
+        }
+        Operation::Iterate => {
+            let container = C::filled(size);
+            let start = Instant::now();
+
+            black_box(container.sum());
+
+            start.elapsed()
+        }
+        Operation::Retain => {
+            let mut container = C::filled(size);
+            let start = Instant::now();
+
+            container.retain_even();
+
+            let elapsed = start.elapsed();
+
+            black_box(&container);
+
+            elapsed
+        }
+        Operation::Reverse => {
+            let mut container = C::filled(size);
+            let start = Instant::now();
+
+            container.reverse();
+
+            let elapsed = start.elapsed();
+
+            black_box(&container);
+
+            elapsed
+        }
+        Operation::Split => {
+            let container = C::filled(size);
+            let start = Instant::now();
+            let halves = container.split();
+            let elapsed = start.elapsed();
+
+            black_box(&halves);
+
+            elapsed
+        }
+        Operation::Contains => {
+            let container = C::filled(size);
+            let start = Instant::now();
+
+            black_box(container.contains(black_box(size)));
+
+            start.elapsed()
+        }
+        Operation::Drop => {
+            let container = C::filled(size);
+            let start = Instant::now();
+
+            drop(black_box(container));
+
+            start.elapsed()
+        }
+    }
+}
+
+/// Returns the average time per element in nanoseconds, or `None` when the
+/// container does not support `operation` efficiently at this size.
+fn measure<C: Container>(operation: Operation, size: usize) -> Option<f64> {
+    if operation.uses_front() && !C::supports_front(size) {
+        return None;
+    }
+
+    let rounds = MIN_ELEMENTS_PER_MEASUREMENT.div_ceil(size);
+    let total: Duration = (0..rounds).map(|_| run_once::<C>(operation, size)).sum();
+
+    #[allow(clippy::cast_precision_loss)]
+    Some(total.as_nanos() as f64 / (rounds * size) as f64)
+}
+
+struct Row {
+    operation: &'static str,
+    size: usize,
+    container: &'static str,
+    nanos_per_element: Option<f64>,
+}
+
+fn row<C: Container>(operation: Operation, size: usize) -> Row {
+    Row {
+        operation: operation.name(),
+        size,
+        container: C::NAME,
+        nanos_per_element: measure::<C>(operation, size),
+    }
+}
+
+fn format_nanos(nanos: Option<f64>) -> String {
+    nanos.map_or_else(|| "n/a".to_string(), |nanos| format!("{nanos:.2}"))
+}
+
+fn print_csv(rows: &[Row]) {
+    println!("operation,size,container,ns_per_element");
+
+    for row in rows {
+        println!(
+            "{},{},{},{}",
+            row.operation,
+            row.size,
+            row.container,
+            format_nanos(row.nanos_per_element)
+        );
+    }
+}
+
+fn print_markdown(rows: &[Row]) {
+    println!("| operation | size | container | ns/element |");
+    println!("|---|---:|---|---:|");
+
+    for row in rows {
+        println!(
+            "| {} | {} | {} | {} |",
+            row.operation,
+            row.size,
+            row.container,
+            format_nanos(row.nanos_per_element)
+        );
+    }
+}
+
+fn main() {
+    let mut csv = false;
+    let mut max_size = usize::MAX;
+    let mut args = env::args().skip(1);
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--csv" => csv = true,
+            "--max-size" => {
+                max_size = args
+                    .next()
+                    .and_then(|value| value.parse().ok())
+                    .expect("--max-size expects a number");
+            }
+            // Passed by `cargo bench`.
+            "--bench" => {}
+            other => panic!("unknown argument: {other}"),
+        }
+    }
+
+    let mut rows = vec![];
+
+    for operation in Operation::ALL {
+        for size in SIZES.into_iter().filter(|size| *size <= max_size) {
+            rows.push(row::<LinkedList<usize>>(operation, size));
+            rows.push(row::<collections::LinkedList<usize>>(operation, size));
+            rows.push(row::<VecDeque<usize>>(operation, size));
+            rows.push(row::<Vec<usize>>(operation, size));
+        }
+    }
+
+    if csv {
+        print_csv(&rows);
+    } else {
+        print_markdown(&rows);
+    }
+}
@@ -0,0 +1,90 @@
+#![deny(clippy::all)]
+#![deny(clippy::pedantic)]
+#![deny(clippy::nursery)]
+
+//! Replays fuzzing inputs through `linked_list::fuzz_ops`.
+//!
+//! Usage: `cargo run --features fuzzing --bin fuzz-replay -- <file or directory>...`
+//!
+//! Every file given directly or found in a given directory is decoded as one
+//! input. Failing inputs are listed and the process exits with status 1.
+
+use std::{
+    env, fs, io,
+    panic::{self, AssertUnwindSafe},
+    path::{Path, PathBuf},
+    process::ExitCode,
+};
+
+use linked_list::fuzz_ops;
+
+fn collect_inputs(path: &Path, inputs: &mut Vec<PathBuf>) -> io::Result<()> {
+    if path.is_dir() {
+        let mut entries = fs::read_dir(path)?
+            .map(|entry| entry.map(|entry| entry.path()))
+            .collect::<io::Result<Vec<_>>>()?;
+
+        entries.sort();
+
+        for entry in entries {
+            collect_inputs(&entry, inputs)?;
+        }
+    } else {
+        inputs.push(path.to_path_buf());
+    }
+
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let paths: Vec<PathBuf> = env::args_os().skip(1).map(PathBuf::from).collect();
+
+    if paths.is_empty() {
+        eprintln!("usage: fuzz-replay <file or directory>...");
+
+        return ExitCode::FAILURE;
+    }
+
+    let mut inputs = vec![];
+
+    for path in &paths {
+        if let Err(error) = collect_inputs(path, &mut inputs) {
+            eprintln!("{}: {error}", path.display());
+
+            return ExitCode::FAILURE;
+        }
+    }
+
+    let mut failures = vec![];
+
+    for input in &inputs {
+        let data = match fs::read(input) {
+            Ok(data) => data,
+            Err(error) => {
+                eprintln!("{}: {error}", input.display());
+
+                return ExitCode::FAILURE;
+            }
+        };
+
+        if panic::catch_unwind(AssertUnwindSafe(|| fuzz_ops(&data))).is_err() {
+            failures.push(input);
+        }
+    }
+
+    println!(
+        "replayed {} inputs, {} failed",
+        inputs.len(),
+        failures.len()
+    );
+
+    for failure in &failures {
+        println!("failed: {}", failure.display());
+    }
+
+    if failures.is_empty() {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
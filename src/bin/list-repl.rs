@@ -0,0 +1,488 @@
+#![deny(clippy::all)]
+#![deny(clippy::pedantic)]
+#![deny(clippy::nursery)]
+
+//! An interactive shell manipulating a `LinkedList<i64>`.
+//!
+//! Usage: `cargo run --bin list-repl -- [--diagram] [script]...`
+//!
+//! Every script is replayed line by line before commands are read from stdin.
+//! Type `help` for the list of commands.
+
+use std::{
+    env,
+    fmt::Display,
+    fs,
+    io::{self, IsTerminal, Write},
+    mem,
+    process::ExitCode,
+};
+
+use linked_list::LinkedList;
+
+const HELP: &str = "\
+commands:
+  push_front <n>    add <n> to the front
+  push_back <n>     add <n> to the back
+  pop_front         remove the front element
+  pop_back          remove the back element
+  front, back       show the front or back element
+  len               show the number of elements
+  contains <n>      check whether <n> is in the list
+  retain even|odd   keep only the even or odd elements
+  reverse           reverse the list
+  split             keep the front half and show the back half
+  iter              show every element with its index
+  clear             remove every element
+  diagram on|off    show the list as a pointer diagram after each command
+  help              show this message
+  quit, exit        leave the shell
+lines starting with `#` are ignored";
+
+#[derive(Debug, PartialEq, Eq)]
+enum Command {
+    PushFront(i64),
+    PushBack(i64),
+    PopFront,
+    PopBack,
+    Front,
+    Back,
+    Len,
+    Contains(i64),
+    Retain { even: bool },
+    Reverse,
+    Split,
+    Iter,
+    Clear,
+    Diagram(bool),
+    Help,
+    Quit,
+}
+
+impl Command {
+    fn parse(line: &str) -> Result<Self, String> {
+        let mut words = line.split_whitespace();
+        let name = words.next().unwrap_or_default();
+        let argument = words.next();
+
+        if let Some(extra) = words.next() {
+            return Err(format!("unexpected argument `{extra}`"));
+        }
+
+        let number = || {
+            let argument = argument.ok_or_else(|| format!("`{name}` expects a number"))?;
+
+            argument
+                .parse()
+                .map_err(|error| format!("invalid number `{argument}`: {error}"))
+        };
+        let none = |command| {
+            argument.map_or(Ok(command), |argument| {
+                Err(format!("`{name}` takes no argument, got `{argument}`"))
+            })
+        };
+
+        match name {
+            "push_front" => number().map(Self::PushFront),
+            "push_back" => number().map(Self::PushBack),
+            "contains" => number().map(Self::Contains),
+            "retain" => match argument {
+                Some("even") => Ok(Self::Retain { even: true }),
+                Some("odd") => Ok(Self::Retain { even: false }),
+                _ => Err("`retain` expects `even` or `odd`".to_string()),
+            },
+            "diagram" => match argument {
+                Some("on") => Ok(Self::Diagram(true)),
+                Some("off") => Ok(Self::Diagram(false)),
+                _ => Err("`diagram` expects `on` or `off`".to_string()),
+            },
+            "pop_front" => none(Self::PopFront),
+            "pop_back" => none(Self::PopBack),
+            "front" => none(Self::Front),
+            "back" => none(Self::Back),
+            "len" => none(Self::Len),
+            "reverse" => none(Self::Reverse),
+            "split" => none(Self::Split),
+            "iter" => none(Self::Iter),
+            "clear" => none(Self::Clear),
+            "help" => none(Self::Help),
+            "quit" | "exit" => none(Self::Quit),
+            _ => Err(format!("unknown command `{name}`, type `help` for a list")),
+        }
+    }
+}
+
+struct Repl<W> {
+    list: LinkedList<i64>,
+    diagram: bool,
+    output: W,
+}
+
+impl<W: Write> Repl<W> {
+    fn print(&mut self, text: impl Display) -> Result<(), String> {
+        writeln!(self.output, "{text}").map_err(|error| format!("output: {error}"))
+    }
+
+    fn show(&mut self) -> Result<(), String> {
+        if self.diagram {
+            let diagram = self.list.ascii_diagram();
+
+            self.print(diagram.trim_end_matches('\n'))
+        } else {
+            let list = self.list.to_string();
+
+            self.print(list)
+        }
+    }
+
+    fn show_element(&mut self, element: Option<i64>) -> Result<(), String> {
+        match element {
+            Some(element) => self.print(element),
+            None => self.print("(empty)"),
+        }
+    }
+
+    /// Runs one line of input, returning `false` once the shell should exit.
+    fn run_line(&mut self, line: &str) -> Result<bool, String> {
+        let line = line.trim();
+
+        if line.is_empty() || line.starts_with('#') {
+            return Ok(true);
+        }
+
+        // Commands that only inspect the list do not print it again.
+        let changed = match Command::parse(line)? {
+            Command::PushFront(element) => {
+                self.list.push_front(element);
+
+                true
+            }
+            Command::PushBack(element) => {
+                self.list.push_back(element);
+
+                true
+            }
+            Command::PopFront => {
+                let element = self.list.pop_front();
+
+                self.show_element(element)?;
+
+                true
+            }
+            Command::PopBack => {
+                let element = self.list.pop_back();
+
+                self.show_element(element)?;
+
+                true
+            }
+            Command::Front => {
+                self.show_element(self.list.front().copied())?;
+
+                false
+            }
+            Command::Back => {
+                self.show_element(self.list.back().copied())?;
+
+                false
+            }
+            Command::Len => {
+                self.print(self.list.len())?;
+
+                false
+            }
+            Command::Contains(element) => {
+                self.print(self.list.contains(&element))?;
+
+                false
+            }
+            Command::Retain { even } => {
+                self.list.retain(|element| (element % 2 == 0) == even);
+
+                true
+            }
+            Command::Reverse => {
+                self.list.reverse();
+
+                true
+            }
+            Command::Split => {
+                let (front, back) = mem::take(&mut self.list).split();
+
+                self.list = front;
+                self.print(format_args!("back half: {back}"))?;
+
+                true
+            }
+            Command::Iter => {
+                for (index, element) in self.list.iter().enumerate() {
+                    writeln!(self.output, "{index}: {element}")
+                        .map_err(|error| format!("output: {error}"))?;
+                }
+
+                false
+            }
+            Command::Clear => {
+                self.list.clear();
+
+                true
+            }
+            Command::Diagram(enabled) => {
+                self.diagram = enabled;
+
+                true
+            }
+            Command::Help => {
+                self.print(HELP)?;
+
+                false
+            }
+            Command::Quit => return Ok(false),
+        };
+
+        if changed {
+            self.show()?;
+        }
+
+        Ok(true)
+    }
+
+    /// Replays a script, echoing every command. Returns `false` if the script
+    /// asked to quit.
+    fn run_script(&mut self, path: &str) -> io::Result<bool> {
+        let script = fs::read_to_string(path)?;
+
+        for (number, line) in script.lines().enumerate() {
+            if !line.trim().is_empty() && !line.trim_start().starts_with('#') {
+                writeln!(self.output, "> {}", line.trim())?;
+            }
+
+            match self.run_line(line) {
+                Ok(true) => {}
+                Ok(false) => return Ok(false),
+                Err(error) => eprintln!("{path}:{}: {error}", number + 1),
+            }
+        }
+
+        Ok(true)
+    }
+}
+
+fn main() -> ExitCode {
+    let mut repl = Repl {
+        list: LinkedList::new(),
+        diagram: false,
+        output: io::stdout(),
+    };
+    let mut scripts = vec![];
+
+    for arg in env::args().skip(1) {
+        match arg.as_str() {
+            "--diagram" => repl.diagram = true,
+            "--help" | "-h" => {
+                println!("usage: list-repl [--diagram] [script]...\n\n{HELP}");
+
+                return ExitCode::SUCCESS;
+            }
+            _ => scripts.push(arg),
+        }
+    }
+
+    for script in &scripts {
+        match repl.run_script(script) {
+            Ok(true) => {}
+            Ok(false) => return ExitCode::SUCCESS,
+            Err(error) => {
+                eprintln!("{script}: {error}");
+
+                return ExitCode::FAILURE;
+            }
+        }
+    }
+
+    let interactive = io::stdin().is_terminal();
+
+    if interactive {
+        println!("type `help` for a list of commands");
+    }
+
+    let mut line = String::new();
+
+    loop {
+        if interactive {
+            print!("> ");
+
+            let _ = io::stdout().flush();
+        }
+
+        line.clear();
+
+        match io::stdin().read_line(&mut line) {
+            Ok(0) => return ExitCode::SUCCESS,
+            Ok(_) => {}
+            Err(error) => {
+                eprintln!("stdin: {error}");
+
+                return ExitCode::FAILURE;
+            }
+        }
+
+        match repl.run_line(&line) {
+            Ok(true) => {}
+            Ok(false) => return ExitCode::SUCCESS,
+            Err(error) => eprintln!("error: {error}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use linked_list::LinkedList;
+
+    use super::{Command, Repl};
+
+    fn run(lines: &[&str]) -> (String, Vec<String>) {
+        let mut repl = Repl {
+            list: LinkedList::new(),
+            diagram: false,
+            output: vec![],
+        };
+        let mut errors = vec![];
+
+        for line in lines {
+            match repl.run_line(line) {
+                Ok(true) => {}
+                Ok(false) => break,
+                Err(error) => errors.push(error),
+            }
+        }
+
+        let output = String::from_utf8(repl.output).expect("output is valid UTF-8");
+
+        (output, errors)
+    }
+
+    #[test]
+    fn parses_every_command() {
+        let commands = [
+            ("push_front 1", Command::PushFront(1)),
+            ("push_back -2", Command::PushBack(-2)),
+            ("pop_front", Command::PopFront),
+            ("pop_back", Command::PopBack),
+            ("front", Command::Front),
+            ("back", Command::Back),
+            ("len", Command::Len),
+            ("contains 3", Command::Contains(3)),
+            ("retain even", Command::Retain { even: true }),
+            ("retain odd", Command::Retain { even: false }),
+            ("reverse", Command::Reverse),
+            ("split", Command::Split),
+            ("iter", Command::Iter),
+            ("clear", Command::Clear),
+            ("diagram on", Command::Diagram(true)),
+            ("diagram off", Command::Diagram(false)),
+            ("help", Command::Help),
+            ("quit", Command::Quit),
+            ("exit", Command::Quit),
+            ("  push_back   4  ", Command::PushBack(4)),
+        ];
+
+        for (line, command) in commands {
+            assert_eq!(Command::parse(line), Ok(command), "{line}");
+        }
+    }
+
+    #[test]
+    fn rejects_bad_arguments() {
+        let errors = [
+            ("push_front", "`push_front` expects a number"),
+            (
+                "push_back x",
+                "invalid number `x`: invalid digit found in string",
+            ),
+            ("contains 1 2", "unexpected argument `2`"),
+            ("pop_front 1", "`pop_front` takes no argument, got `1`"),
+            ("retain", "`retain` expects `even` or `odd`"),
+            ("retain all", "`retain` expects `even` or `odd`"),
+            ("diagram yes", "`diagram` expects `on` or `off`"),
+            (
+                "insert 1",
+                "unknown command `insert`, type `help` for a list",
+            ),
+        ];
+
+        for (line, error) in errors {
+            assert_eq!(Command::parse(line), Err(error.to_string()), "{line}");
+        }
+    }
+
+    #[test]
+    fn runs_a_session() {
+        let (output, errors) = run(&[
+            "# build a list",
+            "push_back 1",
+            "push_back 2",
+            "push_front 0",
+            "",
+            "len",
+            "front",
+            "contains 2",
+            "oops",
+            "iter",
+            "retain even",
+            "reverse",
+            "pop_back",
+            "split",
+            "pop_front",
+            "back",
+            "quit",
+            "push_back 9",
+        ]);
+
+        assert_eq!(
+            output,
+            concat!(
+                "[1]\n",
+                "[1 <-> 2]\n",
+                "[0 <-> 1 <-> 2]\n",
+                "3\n",
+                "0\n",
+                "true\n",
+                "0: 0\n",
+                "1: 1\n",
+                "2: 2\n",
+                "[0 <-> 2]\n",
+                "[2 <-> 0]\n",
+                "0\n",
+                "[2]\n",
+                "back half: []\n",
+                "[2]\n",
+                "2\n",
+                "[]\n",
+                "(empty)\n",
+            )
+        );
+        assert_eq!(
+            errors,
+            ["unknown command `oops`, type `help` for a list".to_string()]
+        );
+    }
+
+    #[test]
+    fn shows_diagrams_when_enabled() {
+        let (output, errors) = run(&["diagram on", "push_back 7", "diagram off"]);
+
+        assert!(errors.is_empty());
+        assert_eq!(
+            output,
+            concat!(
+                "head -> NULL <- tail\n",
+                "        head/tail\n",
+                "        +-------+\n",
+                "NULL <- |   7   | -> NULL\n",
+                "        +-------+\n",
+                "[7]\n",
+            )
+        );
+    }
+}
@@ -0,0 +1,162 @@
+use std::{
+    error::Error,
+    fmt::{self, Display},
+    io::{self, Read, Write},
+};
+
+use super::LinkedList;
+
+const MAGIC: [u8; 4] = *b"LLST";
+const VERSION: u8 = 1;
+
+/// A type that can be written in the binary list format.
+///
+/// Integers and floats are stored little-endian with their own width, `usize`
+/// and `isize` as 64 bits. Strings and byte vectors are prefixed with their
+/// length as a `u64`, options with a tag byte, and tuples are stored field by
+/// field.
+pub trait Encode {
+    /// Appends the encoding of `self` to `writer`.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error of `writer`.
+    fn encode<W: Write>(&self, writer: &mut W) -> io::Result<()>;
+}
+
+/// A type that can be read back from the binary list format.
+pub trait Decode: Sized {
+    /// Reads one value written by `Encode::encode`, consuming exactly its
+    /// bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error of `reader`, or a `DecodeError` describing malformed
+    /// input.
+    fn decode<R: Read>(reader: &mut R) -> Result<Self, DecodeError>;
+}
+
+/// An error returned when binary input cannot be decoded.
+#[derive(Debug)]
+pub enum DecodeError {
+    Io(io::Error),
+    /// The input does not start with the magic bytes of a list.
+    InvalidMagic,
+    /// The list was written by an unknown version of the format.
+    UnsupportedVersion(u8),
+    /// A `bool` or `Option` tag is neither `0` nor `1`.
+    InvalidTag(u8),
+    /// A string is not valid UTF-8.
+    InvalidUtf8,
+    /// A length or size does not fit in `usize` on this platform.
+    LengthOverflow,
+    /// A `usize` or `isize` element does not fit in its type on this
+    /// platform.
+    IntegerOverflow,
+}
+
+impl Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(error) => write!(f, "{error}"),
+            Self::InvalidMagic => write!(f, "input does not start with a list header"),
+            Self::UnsupportedVersion(version) => {
+                write!(f, "unsupported list format version {version}")
+            }
+            Self::InvalidTag(tag) => write!(f, "invalid tag byte {tag}"),
+            Self::InvalidUtf8 => write!(f, "string is not valid UTF-8"),
+            Self::LengthOverflow => write!(f, "length does not fit in usize"),
+            Self::IntegerOverflow => write!(f, "integer does not fit in usize or isize"),
+        }
+    }
+}
+
+impl Error for DecodeError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Io(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+/// Writes `len` as a `u64` prefix.
+pub fn encode_len<W: Write>(len: usize, writer: &mut W) -> io::Result<()> {
+    u64::try_from(len)
+        .expect("lengths fit in u64")
+        .encode(writer)
+}
+
+pub fn decode_len<R: Read>(reader: &mut R) -> Result<usize, DecodeError> {
+    usize::try_from(u64::decode(reader)?).map_err(|_| DecodeError::LengthOverflow)
+}
+
+/// Reads a length-prefixed run of bytes. The buffer only grows with the
+/// bytes actually read, so a corrupted length cannot exhaust memory.
+pub fn decode_bytes<R: Read>(reader: &mut R) -> Result<Vec<u8>, DecodeError> {
+    let len = u64::decode(reader)?;
+    let mut bytes = vec![];
+
+    reader.take(len).read_to_end(&mut bytes)?;
+
+    if u64::try_from(bytes.len()) != Ok(len) {
+        return Err(DecodeError::Io(io::ErrorKind::UnexpectedEof.into()));
+    }
+
+    Ok(bytes)
+}
+
+pub fn decode_tag<R: Read>(reader: &mut R) -> Result<bool, DecodeError> {
+    match u8::decode(reader)? {
+        0 => Ok(false),
+        1 => Ok(true),
+        tag => Err(DecodeError::InvalidTag(tag)),
+    }
+}
+
+/// Writes the header, the number of elements and then each element in
+/// turn, so nothing but the element being encoded is buffered.
+pub fn write_list<T, W>(list: &LinkedList<T>, writer: &mut W) -> io::Result<()>
+where
+    T: Encode,
+    W: Write,
+{
+    writer.write_all(&MAGIC)?;
+    writer.write_all(&[VERSION])?;
+    encode_len(list.len(), writer)?;
+
+    for element in list {
+        element.encode(writer)?;
+    }
+
+    Ok(())
+}
+
+/// Reads a list written by `write_list`, pushing each element as soon as it
+/// is decoded. Input after the last element is left unread.
+pub fn read_list<T, R>(reader: &mut R) -> Result<LinkedList<T>, DecodeError>
+where
+    T: Decode,
+    R: Read,
+{
+    let mut header = [0; 5];
+
+    reader.read_exact(&mut header)?;
+
+    if header[..4] != MAGIC {
+        return Err(DecodeError::InvalidMagic);
+    }
+
+    if header[4] != VERSION {
+        return Err(DecodeError::UnsupportedVersion(header[4]));
+    }
+
+    let len = decode_len(reader)?;
+    let mut list = LinkedList::new();
+
+    for _ in 0..len {
+        list.push_back(T::decode(reader)?);
+    }
+
+    Ok(list)
+}
@@ -0,0 +1,332 @@
+use std::{
+    error::Error,
+    fmt::{self, Display},
+    sync::{Arc, Condvar, Mutex, MutexGuard, PoisonError},
+    time::{Duration, Instant},
+};
+
+use super::LinkedList;
+
+pub struct State<T> {
+    pub(super) queue: LinkedList<T>,
+    pub(super) senders: usize,
+    pub(super) receiver_alive: bool,
+}
+
+pub struct Shared<T> {
+    state: Mutex<State<T>>,
+    pub(super) not_empty: Condvar,
+    pub(super) not_full: Condvar,
+    capacity: Option<usize>,
+}
+
+impl<T> Shared<T> {
+    /// The queue is only edited through non-panicking list operations, so a
+    /// poisoned lock still guards a consistent state.
+    pub(super) fn lock(&self) -> MutexGuard<'_, State<T>> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn is_full(&self, state: &State<T>) -> bool {
+        self.capacity
+            .is_some_and(|capacity| state.queue.len() >= capacity)
+    }
+}
+
+/// The sending half of a channel created by `channel`. It can be cloned to
+/// send from several threads.
+pub struct Sender<T> {
+    pub(super) shared: Arc<Shared<T>>,
+}
+
+/// The receiving half of a channel created by `channel`.
+pub struct Receiver<T> {
+    pub(super) shared: Arc<Shared<T>>,
+}
+
+/// A blocking iterator over received elements, ending once every sender is
+/// gone.
+pub struct Iter<'a, T> {
+    pub(super) receiver: &'a Receiver<T>,
+}
+
+/// An iterator over the elements that are already queued.
+pub struct TryIter<'a, T> {
+    pub(super) receiver: &'a Receiver<T>,
+}
+
+pub struct IntoIter<T> {
+    pub(super) receiver: Receiver<T>,
+}
+
+/// Creates a channel whose queue is a `LinkedList`.
+///
+/// With `Some(capacity)` the channel holds at most `capacity` elements and
+/// `send` blocks while it is full. With `None` it is unbounded.
+///
+/// # Panics
+///
+/// Panics if `capacity` is `Some(0)`.
+#[must_use]
+pub fn channel<T>(capacity: Option<usize>) -> (Sender<T>, Receiver<T>) {
+    assert!(capacity != Some(0), "channel capacity must be positive");
+
+    let shared = Arc::new(Shared {
+        state: Mutex::new(State {
+            queue: LinkedList::new(),
+            senders: 1,
+            receiver_alive: true,
+        }),
+        not_empty: Condvar::new(),
+        not_full: Condvar::new(),
+        capacity,
+    });
+
+    (
+        Sender {
+            shared: Arc::clone(&shared),
+        },
+        Receiver { shared },
+    )
+}
+
+/// Returned by `Sender::send` when the receiver is gone, handing back the
+/// element.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SendError<T>(pub T);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TrySendError<T> {
+    /// The channel is at capacity.
+    Full(T),
+    /// The receiver is gone.
+    Disconnected(T),
+}
+
+/// Returned by `Receiver::recv` when the channel is empty and every sender is
+/// gone.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RecvError;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TryRecvError {
+    /// No element is queued yet.
+    Empty,
+    /// No element is queued and every sender is gone.
+    Disconnected,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RecvTimeoutError {
+    /// No element arrived before the timeout.
+    Timeout,
+    /// No element is queued and every sender is gone.
+    Disconnected,
+}
+
+impl<T> Display for SendError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "sending on a disconnected channel")
+    }
+}
+
+impl<T: fmt::Debug> Error for SendError<T> {}
+
+impl<T> Display for TrySendError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Full(_) => write!(f, "sending on a full channel"),
+            Self::Disconnected(_) => write!(f, "sending on a disconnected channel"),
+        }
+    }
+}
+
+impl<T: fmt::Debug> Error for TrySendError<T> {}
+
+impl Display for RecvError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "receiving on an empty and disconnected channel")
+    }
+}
+
+impl Error for RecvError {}
+
+impl Display for TryRecvError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => write!(f, "receiving on an empty channel"),
+            Self::Disconnected => write!(f, "receiving on an empty and disconnected channel"),
+        }
+    }
+}
+
+impl Error for TryRecvError {}
+
+impl Display for RecvTimeoutError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Timeout => write!(f, "timed out waiting on channel"),
+            Self::Disconnected => write!(f, "channel is empty and disconnected"),
+        }
+    }
+}
+
+impl Error for RecvTimeoutError {}
+
+impl<T> Sender<T> {
+    /// Queues `element`, blocking while the channel is full.
+    ///
+    /// # Errors
+    ///
+    /// Returns the element in a `SendError` if the receiver is gone.
+    pub fn send(&self, element: T) -> Result<(), SendError<T>> {
+        let mut state = self.shared.lock();
+
+        while state.receiver_alive && self.shared.is_full(&state) {
+            state = self
+                .shared
+                .not_full
+                .wait(state)
+                .unwrap_or_else(PoisonError::into_inner);
+        }
+
+        if !state.receiver_alive {
+            return Err(SendError(element));
+        }
+
+        state.queue.push_back(element);
+        drop(state);
+
+        self.shared.not_empty.notify_one();
+
+        Ok(())
+    }
+
+    /// Queues `element` without blocking.
+    ///
+    /// # Errors
+    ///
+    /// Returns the element in a `TrySendError` if the channel is full or the
+    /// receiver is gone.
+    pub fn try_send(&self, element: T) -> Result<(), TrySendError<T>> {
+        let mut state = self.shared.lock();
+
+        if !state.receiver_alive {
+            return Err(TrySendError::Disconnected(element));
+        }
+
+        if self.shared.is_full(&state) {
+            return Err(TrySendError::Full(element));
+        }
+
+        state.queue.push_back(element);
+        drop(state);
+
+        self.shared.not_empty.notify_one();
+
+        Ok(())
+    }
+}
+
+impl<T> Receiver<T> {
+    /// Takes the oldest element from a locked state, waking a blocked sender.
+    fn take(&self, state: &mut State<T>) -> Option<T> {
+        let element = state.queue.pop_front()?;
+
+        self.shared.not_full.notify_one();
+
+        Some(element)
+    }
+
+    /// Takes the oldest element, blocking until one arrives.
+    ///
+    /// # Errors
+    ///
+    /// Returns `RecvError` once the channel is empty and every sender is gone.
+    pub fn recv(&self) -> Result<T, RecvError> {
+        let mut state = self.shared.lock();
+
+        loop {
+            if let Some(element) = self.take(&mut state) {
+                return Ok(element);
+            }
+
+            if state.senders == 0 {
+                return Err(RecvError);
+            }
+
+            state = self
+                .shared
+                .not_empty
+                .wait(state)
+                .unwrap_or_else(PoisonError::into_inner);
+        }
+    }
+
+    /// Takes the oldest element without blocking.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TryRecvError::Empty` if nothing is queued, or
+    /// `TryRecvError::Disconnected` if nothing is queued and every sender is
+    /// gone.
+    pub fn try_recv(&self) -> Result<T, TryRecvError> {
+        let mut state = self.shared.lock();
+
+        self.take(&mut state).ok_or(if state.senders == 0 {
+            TryRecvError::Disconnected
+        } else {
+            TryRecvError::Empty
+        })
+    }
+
+    /// Takes the oldest element, blocking for at most `timeout`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `RecvTimeoutError::Timeout` if nothing arrived in time, or
+    /// `RecvTimeoutError::Disconnected` once the channel is empty and every
+    /// sender is gone.
+    pub fn recv_timeout(&self, timeout: Duration) -> Result<T, RecvTimeoutError> {
+        let Some(deadline) = Instant::now().checked_add(timeout) else {
+            return self.recv().map_err(|_| RecvTimeoutError::Disconnected);
+        };
+        let mut state = self.shared.lock();
+
+        loop {
+            if let Some(element) = self.take(&mut state) {
+                return Ok(element);
+            }
+
+            if state.senders == 0 {
+                return Err(RecvTimeoutError::Disconnected);
+            }
+
+            let now = Instant::now();
+
+            if now >= deadline {
+                return Err(RecvTimeoutError::Timeout);
+            }
+
+            state = self
+                .shared
+                .not_empty
+                .wait_timeout(state, deadline - now)
+                .unwrap_or_else(PoisonError::into_inner)
+                .0;
+        }
+    }
+
+    /// Returns an iterator that blocks for each element and ends once every
+    /// sender is gone.
+    #[must_use]
+    pub const fn iter(&self) -> Iter<'_, T> {
+        Iter { receiver: self }
+    }
+
+    /// Returns an iterator over the elements queued so far.
+    #[must_use]
+    pub const fn try_iter(&self) -> TryIter<'_, T> {
+        TryIter { receiver: self }
+    }
+}
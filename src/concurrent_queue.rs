@@ -0,0 +1,126 @@
+use std::{
+    cell::UnsafeCell,
+    hint,
+    ptr::{self, NonNull},
+    sync::atomic::{AtomicBool, AtomicPtr, Ordering},
+    thread,
+};
+
+use super::{
+    node::QueueNode,
+    node_allocator::{allocate_node, deallocate_node},
+};
+
+/// Number of spins before a consumer waiting for a half-finished push yields
+/// its time slice.
+const SPINS_BEFORE_YIELD: usize = 64;
+
+/// An unbounded multi-producer single-consumer queue.
+///
+/// Producers link their node in with a single atomic swap of `head`, as in
+/// Dmitry Vyukov's non-intrusive MPSC queue, so `push` never blocks. Elements
+/// are taken from `tail` through a `QueueConsumer`, of which at most one
+/// exists at a time.
+pub struct ConcurrentQueue<T> {
+    /// The most recently pushed node, swapped by producers.
+    pub(super) head: AtomicPtr<QueueNode<T>>,
+    /// The stub node in front of the oldest element, owned by the consumer.
+    pub(super) tail: UnsafeCell<*mut QueueNode<T>>,
+    pub(super) consumer_taken: AtomicBool,
+}
+
+/// The single consumer of a `ConcurrentQueue`.
+pub struct QueueConsumer<'a, T> {
+    pub(super) queue: &'a ConcurrentQueue<T>,
+}
+
+impl<T> ConcurrentQueue<T> {
+    #[must_use]
+    pub fn new() -> Self {
+        let stub = allocate_node(QueueNode::stub()).as_ptr();
+
+        Self {
+            head: AtomicPtr::new(stub),
+            tail: UnsafeCell::new(stub),
+            consumer_taken: AtomicBool::new(false),
+        }
+    }
+
+    pub fn push(&self, element: T) {
+        let node = allocate_node(QueueNode::new(element)).as_ptr();
+        let previous = self.head.swap(node, Ordering::AcqRel);
+
+        // Until this store the node is unreachable from the tail, which the
+        // consumer sees as a push in progress.
+        unsafe { (*previous).next.store(node, Ordering::Release) };
+    }
+
+    /// Returns the consumer, or `None` while another consumer is alive.
+    pub fn consumer(&self) -> Option<QueueConsumer<'_, T>> {
+        self.consumer_taken
+            .compare_exchange(false, true, Ordering::Acquire, Ordering::Relaxed)
+            .ok()
+            .map(|_| QueueConsumer { queue: self })
+    }
+
+    /// Takes the oldest element.
+    ///
+    /// # Safety
+    ///
+    /// The caller must be the only thread accessing `tail`, either through the
+    /// queue's consumer or through exclusive access to the queue.
+    pub(super) unsafe fn pop_unchecked(&self) -> Option<T> {
+        unsafe {
+            let tail = *self.tail.get();
+            let mut next = (*tail).next.load(Ordering::Acquire);
+
+            if next.is_null() {
+                if ptr::eq(self.head.load(Ordering::Acquire), tail) {
+                    return None;
+                }
+
+                // A producer has swapped `head` but not linked its node yet.
+                let mut spins = 0;
+
+                while next.is_null() {
+                    if spins < SPINS_BEFORE_YIELD {
+                        spins += 1;
+                        hint::spin_loop();
+                    } else {
+                        thread::yield_now();
+                    }
+
+                    next = (*tail).next.load(Ordering::Acquire);
+                }
+            }
+
+            // `next` becomes the new stub once its element is taken.
+            *self.tail.get() = next;
+
+            let element = (*next).element.assume_init_read();
+
+            deallocate_node(NonNull::new_unchecked(tail));
+
+            Some(element)
+        }
+    }
+
+    /// Frees the stub node once every element has been popped.
+    ///
+    /// # Safety
+    ///
+    /// The queue must be empty and must not be used afterwards.
+    pub(super) unsafe fn deallocate_stub(&mut self) {
+        unsafe { deallocate_node(NonNull::new_unchecked(*self.tail.get_mut())) };
+    }
+}
+
+impl<T> QueueConsumer<'_, T> {
+    /// Takes the oldest element, or returns `None` if the queue is empty.
+    ///
+    /// A push that has started but not finished is waited for, so elements
+    /// pushed before this call are never missed.
+    pub fn pop(&mut self) -> Option<T> {
+        unsafe { self.queue.pop_unchecked() }
+    }
+}
@@ -0,0 +1,96 @@
+use std::sync::Arc;
+
+use super::{LinkedList, list::ListIterMut};
+
+/// A `LinkedList` shared behind an `Arc` that is copied on first write.
+///
+/// `clone` only bumps a reference count, and reads go straight to the shared
+/// list through `Deref`. Every mutating method deep-copies the list first if
+/// another `CowList` still refers to it, mirroring `Arc::make_mut`.
+pub struct CowList<T> {
+    pub(super) list: Arc<LinkedList<T>>,
+}
+
+impl<T> CowList<T> {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            list: Arc::new(LinkedList::new()),
+        }
+    }
+
+    /// Returns whether no other `CowList` shares this list, in which case
+    /// mutations do not copy it.
+    #[must_use]
+    pub fn is_unique(&self) -> bool {
+        Arc::strong_count(&self.list) == 1
+    }
+
+    /// Returns whether both lists share the same nodes.
+    #[must_use]
+    pub fn ptr_eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.list, &other.list)
+    }
+}
+
+impl<T: Clone> CowList<T> {
+    /// Returns a mutable reference to the list, copying it first if it is
+    /// shared.
+    pub fn make_mut(&mut self) -> &mut LinkedList<T> {
+        Arc::make_mut(&mut self.list)
+    }
+
+    /// Returns the list, copying it if it is shared.
+    #[must_use]
+    pub fn into_list(self) -> LinkedList<T> {
+        Arc::unwrap_or_clone(self.list)
+    }
+
+    pub fn push_front(&mut self, element: T) {
+        self.make_mut().push_front(element);
+    }
+
+    pub fn push_back(&mut self, element: T) {
+        self.make_mut().push_back(element);
+    }
+
+    pub fn pop_front(&mut self) -> Option<T> {
+        self.make_mut().pop_front()
+    }
+
+    pub fn pop_back(&mut self) -> Option<T> {
+        self.make_mut().pop_back()
+    }
+
+    pub fn front_mut(&mut self) -> Option<&mut T> {
+        self.make_mut().front_mut()
+    }
+
+    pub fn back_mut(&mut self) -> Option<&mut T> {
+        self.make_mut().back_mut()
+    }
+
+    /// Empties the list. A shared list is released rather than copied.
+    pub fn clear(&mut self) {
+        if self.is_unique() {
+            self.make_mut().clear();
+        } else {
+            *self = Self::new();
+        }
+    }
+
+    pub fn reverse(&mut self) {
+        self.make_mut().reverse();
+    }
+
+    pub fn retain<F>(&mut self, predicate: F)
+    where
+        F: Fn(&T) -> bool,
+    {
+        self.make_mut().retain(predicate);
+    }
+
+    pub fn iter_mut(&mut self) -> ListIterMut<'_, T> {
+        self.make_mut().iter_mut()
+    }
+}
@@ -0,0 +1,198 @@
+use std::fmt::{Display, Write};
+
+use super::LinkedList;
+
+/// Controls how `LinkedList::ascii_diagram_with` lays out the diagram.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DiagramOptions {
+    /// Maximum line width before the diagram wraps onto a new row of boxes.
+    /// A row always holds at least one box, even if it is wider than this.
+    pub width: usize,
+}
+
+/// Records the diagram of a list around each operation applied through it.
+pub struct DiagramTrace {
+    options: DiagramOptions,
+    show_before: bool,
+    output: String,
+}
+
+impl DiagramTrace {
+    /// Creates an empty trace. With `show_before` set, every step renders the
+    /// list before the operation as well as after it.
+    #[must_use]
+    pub const fn new(options: DiagramOptions, show_before: bool) -> Self {
+        Self {
+            options,
+            show_before,
+            output: String::new(),
+        }
+    }
+
+    /// Applies `operation` to `list`, recording its diagram under `label`.
+    pub fn step<T, R, F>(&mut self, list: &mut LinkedList<T>, label: &str, operation: F) -> R
+    where
+        T: Display,
+        F: FnOnce(&mut LinkedList<T>) -> R,
+    {
+        let _ = writeln!(self.output, "== {label} ==");
+
+        if self.show_before {
+            let _ = writeln!(self.output, "before:");
+            self.output.push_str(&list.ascii_diagram_with(self.options));
+            let _ = writeln!(self.output, "after:");
+        }
+
+        let result = operation(list);
+
+        self.output.push_str(&list.ascii_diagram_with(self.options));
+
+        result
+    }
+
+    #[must_use]
+    pub fn output(&self) -> &str {
+        &self.output
+    }
+
+    #[must_use]
+    pub fn into_output(self) -> String {
+        self.output
+    }
+}
+
+/// A column of the diagram: a label above a three line box or connector.
+struct Cell {
+    label: String,
+    top: String,
+    middle: String,
+    bottom: String,
+}
+
+impl Cell {
+    fn text(middle: &str) -> Self {
+        Self {
+            label: String::new(),
+            top: String::new(),
+            middle: middle.to_string(),
+            bottom: String::new(),
+        }
+    }
+
+    fn node(element: &str, label: &str, connector: Option<&str>) -> Self {
+        let connector = connector.unwrap_or("");
+        let padding = " ".repeat(connector.len());
+        // Widened so that a `head/tail` label does not overhang the box.
+        let inner = (element.chars().count() + 2).max(label.len().saturating_sub(2));
+        let border = format!("+{}+", "-".repeat(inner));
+
+        Self {
+            label: format!("{padding}{label}"),
+            top: format!("{padding}{border}"),
+            middle: format!("{connector}|{element:^inner$}|"),
+            bottom: format!("{padding}{border}"),
+        }
+    }
+
+    fn width(&self) -> usize {
+        [&self.label, &self.top, &self.middle, &self.bottom]
+            .iter()
+            .map(|line| line.chars().count())
+            .max()
+            .unwrap_or(0)
+    }
+}
+
+fn write_row(output: &mut String, row: &[Cell]) {
+    let lines: [fn(&Cell) -> &String; 4] = [
+        |cell| &cell.label,
+        |cell| &cell.top,
+        |cell| &cell.middle,
+        |cell| &cell.bottom,
+    ];
+
+    for line in lines {
+        let mut text = String::new();
+
+        for cell in row {
+            let _ = write!(text, "{:width$}", line(cell), width = cell.width());
+        }
+
+        let text = text.trim_end();
+
+        if !text.is_empty() {
+            output.push_str(text);
+            output.push('\n');
+        }
+    }
+}
+
+pub fn ascii_diagram<T: Display>(list: &LinkedList<T>, options: DiagramOptions) -> String {
+    let Some(head) = list.head else {
+        return "head -> NULL <- tail\n".to_string();
+    };
+
+    let mut cells = vec![];
+
+    if unsafe { head.as_ref().previous }.is_none() {
+        cells.push(Cell::text("NULL <- "));
+    }
+
+    let mut previous_node = None;
+    let mut current_node = Some(head);
+    let mut drawn = 0;
+
+    // Bounded by the size so that a cycle cannot loop forever.
+    while let Some(node) = current_node
+        && drawn < list.size
+    {
+        let node_ref = unsafe { node.as_ref() };
+        let label = match (Some(node) == list.head, Some(node) == list.tail) {
+            (true, true) => "head/tail",
+            (true, false) => "head",
+            (false, true) => "tail",
+            (false, false) => "",
+        };
+        let connector = previous_node.map(|_| {
+            if node_ref.previous == previous_node {
+                " <-> "
+            } else {
+                " --> "
+            }
+        });
+
+        cells.push(Cell::node(&node_ref.element.to_string(), label, connector));
+
+        previous_node = current_node;
+        current_node = node_ref.next;
+        drawn += 1;
+    }
+
+    cells.push(Cell::text(if current_node.is_some() {
+        " --> ..."
+    } else {
+        " -> NULL"
+    }));
+
+    let mut output = String::new();
+    let mut row: Vec<Cell> = vec![];
+    let mut row_width = 0;
+
+    for cell in cells {
+        let width = cell.width();
+
+        if !row.is_empty() && row_width + width > options.width {
+            write_row(&mut output, &row);
+
+            row.clear();
+            row_width = 0;
+        }
+
+        row_width += width;
+        row.push(cell);
+    }
+
+    write_row(&mut output, &row);
+
+    output
+}
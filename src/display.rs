@@ -0,0 +1,26 @@
+use super::LinkedList;
+
+/// Controls how `LinkedList::display_with` renders a list.
+///
+/// The default options match the list's `Display` output, except that
+/// elements are written without escaping.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DisplayOptions<'a> {
+    pub open: &'a str,
+    pub separator: &'a str,
+    pub close: &'a str,
+    /// Renders at most this many elements, followed by `...`.
+    pub max_items: Option<usize>,
+    /// Renders the elements from back to front.
+    pub reverse: bool,
+}
+
+/// Adapter returned by `LinkedList::display_with`.
+///
+/// Width, precision, fill and the other formatting flags are forwarded to
+/// every element. The alternate flag (`{:#}`) prints one element per line,
+/// prefixed with its index in the list.
+pub struct DisplayWith<'a, T> {
+    pub(super) list: &'a LinkedList<T>,
+    pub(super) options: DisplayOptions<'a>,
+}
@@ -0,0 +1,235 @@
+use std::{
+    fmt::Display,
+    io::{self, Write},
+    ptr::NonNull,
+};
+
+use super::{
+    LinkedList,
+    node::{Link, Node},
+};
+
+/// Controls the output of `LinkedList::write_dot_with`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DotOptions {
+    /// Adds each node's address below its element.
+    pub show_addresses: bool,
+}
+
+/// The nodes reached by following `next` from the head, without storing
+/// them: `len` nodes, the last of which links back to the node at
+/// `loop_start` if the links form a cycle.
+struct Walk<'a, T> {
+    list: &'a LinkedList<T>,
+    len: usize,
+    loop_start: Option<usize>,
+}
+
+const fn next_of<T>(link: Link<T>) -> Link<T> {
+    match link {
+        Some(node) => unsafe { node.as_ref().next },
+        None => None,
+    }
+}
+
+impl<'a, T> Walk<'a, T> {
+    /// Measures the walk with Brent's cycle detection, which needs constant
+    /// memory however long the list is.
+    fn new(list: &'a LinkedList<T>) -> Self {
+        let mut walk = Self {
+            list,
+            len: 0,
+            loop_start: None,
+        };
+        let mut power = 1;
+        let mut cycle_len = 1;
+        let mut tortoise = list.head;
+        let mut hare = next_of(list.head);
+
+        while hare.is_some() && hare != tortoise {
+            if power == cycle_len {
+                tortoise = hare;
+                power *= 2;
+                cycle_len = 0;
+            }
+
+            hare = next_of(hare);
+            cycle_len += 1;
+        }
+
+        if hare.is_none() {
+            let mut current_node = list.head;
+
+            while current_node.is_some() {
+                walk.len += 1;
+                current_node = next_of(current_node);
+            }
+
+            return walk;
+        }
+
+        let mut loop_start = 0;
+
+        tortoise = list.head;
+        hare = list.head;
+
+        for _ in 0..cycle_len {
+            hare = next_of(hare);
+        }
+
+        while hare != tortoise {
+            tortoise = next_of(tortoise);
+            hare = next_of(hare);
+            loop_start += 1;
+        }
+
+        walk.len = loop_start + cycle_len;
+        walk.loop_start = Some(loop_start);
+
+        walk
+    }
+
+    /// Returns the position of `node` in the walk, comparing it with the
+    /// `expected` node before walking from the head.
+    fn index_of(
+        &self,
+        node: NonNull<Node<T>>,
+        expected: Option<(usize, NonNull<Node<T>>)>,
+    ) -> Option<usize> {
+        if let Some((index, expected_node)) = expected
+            && expected_node == node
+        {
+            return Some(index);
+        }
+
+        let mut current_node = self.list.head;
+
+        for index in 0..self.len {
+            if current_node == Some(node) {
+                return Some(index);
+            }
+
+            current_node = next_of(current_node);
+        }
+
+        None
+    }
+
+    /// Writes an edge from `source` to `target`. Addresses that are not
+    /// reachable from the head reveal broken links, so they are declared as
+    /// such and never dereferenced.
+    fn write_edge<W: Write>(
+        &self,
+        writer: &mut W,
+        source: &str,
+        target: NonNull<Node<T>>,
+        expected: Option<(usize, NonNull<Node<T>>)>,
+        port: &str,
+        attributes: &str,
+    ) -> io::Result<()> {
+        if let Some(index) = self.index_of(target, expected) {
+            return writeln!(writer, "    {source} -> n{index}{port}{attributes};");
+        }
+
+        writeln!(
+            writer,
+            "    x{target:p} [label=\"unreachable\\n{target:p}\", shape=box, color=red];"
+        )?;
+        writeln!(writer, "    {source} -> x{target:p}{attributes};")
+    }
+}
+
+fn escape_label(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+
+    for c in text.chars() {
+        match c {
+            '{' | '}' | '|' | '<' | '>' | '"' | '\\' => {
+                escaped.push('\\');
+                escaped.push(c);
+            }
+            '\n' => escaped.push_str("\\n"),
+            c => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
+const fn link_label<T>(name: &str, link: Link<T>) -> &str {
+    if link.is_some() { name } else { "null" }
+}
+
+/// Writes each node together with its edges as the list is walked, so only
+/// the element being written is held in memory.
+pub fn write_dot<T, W>(list: &LinkedList<T>, writer: &mut W, options: DotOptions) -> io::Result<()>
+where
+    T: Display,
+    W: Write,
+{
+    let walk = Walk::new(list);
+    let mut previous_node = None;
+    let mut current_node = list.head;
+
+    writeln!(writer, "digraph LinkedList {{")?;
+    writeln!(writer, "    rankdir=LR;")?;
+    writeln!(writer, "    node [shape=record];")?;
+
+    for index in 0..walk.len {
+        let Some(node) = current_node else {
+            break;
+        };
+        let node_ref = unsafe { node.as_ref() };
+        let mut element = escape_label(&node_ref.element.to_string());
+
+        if options.show_addresses {
+            element = format!("{element}\\n{:p}", node.as_ptr());
+        }
+
+        writeln!(
+            writer,
+            "    n{index} [label=\"{{<previous> {}|<element> {element}|<next> {}}}\"];",
+            link_label("previous", node_ref.previous),
+            link_label("next", node_ref.next),
+        )?;
+
+        // `next` leads to the following node of the walk, or back into the
+        // cycle after its last node.
+        if node_ref.next.is_some()
+            && let Some(next_index) = (index + 1 < walk.len)
+                .then_some(index + 1)
+                .or(walk.loop_start)
+        {
+            writeln!(writer, "    n{index}:next -> n{next_index}:element;")?;
+        }
+
+        if let Some(previous) = node_ref.previous {
+            walk.write_edge(
+                writer,
+                &format!("n{index}:previous"),
+                previous,
+                previous_node.map(|previous_node| (index - 1, previous_node)),
+                ":element",
+                " [style=dashed]",
+            )?;
+        }
+
+        previous_node = Some(node);
+        current_node = node_ref.next;
+    }
+
+    writeln!(writer, "    head [shape=plaintext];")?;
+    writeln!(writer, "    tail [shape=plaintext];")?;
+
+    if let Some(head) = list.head {
+        walk.write_edge(writer, "head", head, Some((0, head)), "", "")?;
+    }
+
+    if let Some(tail) = list.tail {
+        let last_node = previous_node.map(|node| (walk.len - 1, node));
+
+        walk.write_edge(writer, "tail", tail, last_node, "", "")?;
+    }
+
+    writeln!(writer, "}}")
+}
@@ -0,0 +1,212 @@
+use std::{
+    cmp::Ordering,
+    fs::{File, OpenOptions},
+    io::{self, BufReader, BufWriter, Read, Write},
+    path::{Path, PathBuf},
+    process,
+    sync::atomic::{self, AtomicUsize},
+};
+
+use super::{LinkedList, sort};
+
+/// Distinguishes the run files of sorts running at the same time.
+static NEXT_SORT_ID: AtomicUsize = AtomicUsize::new(0);
+
+/// Writes elements to and reads them back from the run files of
+/// `external_sort_by`.
+///
+/// Each run records how many elements it holds, so `decode` is called exactly
+/// once per encoded element and does not need to detect the end of the file.
+pub trait RunCodec<T> {
+    /// Appends `element` to `writer`.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error of `writer`.
+    fn encode<W: Write>(&self, element: &T, writer: &mut W) -> io::Result<()>;
+
+    /// Reads back one element written by `encode`.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error of `reader`, or an `InvalidData` error for malformed
+    /// input.
+    fn decode<R: Read>(&self, reader: &mut R) -> io::Result<T>;
+}
+
+/// A temporary run file, removed once dropped.
+pub struct RunFile {
+    pub(super) path: PathBuf,
+}
+
+/// A sorted run on disk together with its next element.
+struct Run<T> {
+    reader: BufReader<File>,
+    remaining: usize,
+    head: Option<T>,
+    _file: RunFile,
+}
+
+impl<T> Run<T> {
+    fn advance<C: RunCodec<T>>(&mut self, codec: &C) -> io::Result<()> {
+        if self.remaining > 0 {
+            self.head = Some(codec.decode(&mut self.reader)?);
+            self.remaining -= 1;
+        }
+
+        Ok(())
+    }
+}
+
+enum Source<T> {
+    /// Every element fit in a single run, so nothing was written to disk.
+    Memory(LinkedList<T>),
+    /// Runs in the order they were read from the source.
+    Runs(Vec<Run<T>>),
+}
+
+/// An iterator over the sorted elements of `external_sort_iter_by`,
+/// k-way merging the run files.
+///
+/// Reading a run may fail, in which case the error is yielded once and the
+/// iteration ends. The run files are removed as they are exhausted, or when
+/// the iterator is dropped.
+pub struct ExternalSortIter<T, F, C> {
+    source: Source<T>,
+    compare: F,
+    codec: C,
+    error: Option<io::Error>,
+}
+
+fn write_run<T, C>(run: &mut LinkedList<T>, path: PathBuf, codec: &C) -> io::Result<Run<T>>
+where
+    C: RunCodec<T>,
+{
+    let file = OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(&path)?;
+    let run_file = RunFile { path };
+    let mut writer = BufWriter::new(file);
+    let len = run.len();
+
+    while let Some(element) = run.pop_front() {
+        codec.encode(&element, &mut writer)?;
+    }
+
+    writer.flush()?;
+
+    let mut run = Run {
+        reader: BufReader::new(File::open(&run_file.path)?),
+        remaining: len,
+        head: None,
+        _file: run_file,
+    };
+
+    run.advance(codec)?;
+
+    Ok(run)
+}
+
+/// Sorts runs of `run_size` elements in memory and spills them to files in
+/// `temp_dir` once the source holds more than one run.
+pub fn sort_runs<T, I, F, C>(
+    source: I,
+    mut compare: F,
+    run_size: usize,
+    temp_dir: &Path,
+    codec: C,
+) -> io::Result<ExternalSortIter<T, F, C>>
+where
+    I: IntoIterator<Item = T>,
+    F: FnMut(&T, &T) -> Ordering,
+    C: RunCodec<T>,
+{
+    assert!(run_size > 0, "run size must be positive");
+
+    let sort_id = NEXT_SORT_ID.fetch_add(1, atomic::Ordering::Relaxed);
+    let mut source = source.into_iter().peekable();
+    let mut runs = vec![];
+
+    loop {
+        let mut run = LinkedList::new();
+
+        for element in source.by_ref().take(run_size) {
+            run.push_back(element);
+        }
+
+        sort::sort_by(&mut run, &mut compare);
+
+        if runs.is_empty() && source.peek().is_none() {
+            return Ok(ExternalSortIter {
+                source: Source::Memory(run),
+                compare,
+                codec,
+                error: None,
+            });
+        }
+
+        let path = temp_dir.join(format!(
+            "linked-list-run-{}-{sort_id}-{}",
+            process::id(),
+            runs.len()
+        ));
+
+        runs.push(write_run(&mut run, path, &codec)?);
+
+        if source.peek().is_none() {
+            break;
+        }
+    }
+
+    Ok(ExternalSortIter {
+        source: Source::Runs(runs),
+        compare,
+        codec,
+        error: None,
+    })
+}
+
+impl<T, F, C> ExternalSortIter<T, F, C>
+where
+    F: FnMut(&T, &T) -> Ordering,
+    C: RunCodec<T>,
+{
+    /// Takes the smallest head among the runs. Ties go to the earliest run,
+    /// which keeps the sort stable.
+    pub(super) fn next_merged(&mut self) -> Option<io::Result<T>> {
+        if let Some(error) = self.error.take() {
+            self.source = Source::Runs(vec![]);
+
+            return Some(Err(error));
+        }
+
+        let runs = match &mut self.source {
+            Source::Memory(list) => return list.pop_front().map(Ok),
+            Source::Runs(runs) => runs,
+        };
+        let mut smallest: Option<(usize, &T)> = None;
+
+        for (index, run) in runs.iter().enumerate() {
+            let Some(head) = &run.head else {
+                continue;
+            };
+
+            if smallest.is_none_or(|(_, current)| (self.compare)(head, current) == Ordering::Less) {
+                smallest = Some((index, head));
+            }
+        }
+
+        let (index, _) = smallest?;
+        let run = &mut runs[index];
+        let element = run.head.take()?;
+
+        if let Err(error) = run.advance(&self.codec) {
+            self.error = Some(error);
+        } else if run.head.is_none() {
+            runs.remove(index);
+        }
+
+        Some(Ok(element))
+    }
+}
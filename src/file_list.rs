@@ -0,0 +1,900 @@
+use std::{
+    error::Error,
+    fmt::{self, Display},
+    fs::{File, OpenOptions},
+    io,
+    marker::PhantomData,
+    path::Path,
+};
+
+use super::InvariantViolation;
+
+const MAGIC: [u8; 4] = *b"LLFL";
+const VERSION: u8 = 2;
+
+/// Magic, version and padding, then the element size, head, tail, free-list
+/// head and length as little-endian `u64`s.
+const HEADER_LEN: usize = 48;
+
+/// A state byte followed by the `previous` and `next` offsets.
+const SLOT_HEADER_LEN: usize = 17;
+
+/// The log of the edit in progress, right after the header: the slot count,
+/// head, tail, free-list head and length before the edit, two slot offsets
+/// each followed by the slot header stored there, and a state byte.
+const LOG_LEN: usize = 5 * 8 + 2 * LOG_SLOT_LEN + 1;
+
+const LOG_SLOT_LEN: usize = 8 + SLOT_HEADER_LEN;
+
+const LOG_OFFSET: u64 = HEADER_LEN as u64;
+
+/// Offset of the first slot, right after the header and the log.
+const FIRST_SLOT: u64 = (HEADER_LEN + LOG_LEN) as u64;
+
+const EDIT_DONE: u8 = 0;
+const EDIT_PENDING: u8 = 1;
+
+const SLOT_USED: u8 = 1;
+const SLOT_FREE: u8 = 2;
+
+/// The offset that stands for a missing link. The header occupies it, so no
+/// slot can start there.
+const NONE: u64 = 0;
+
+/// An element with a fixed-size binary encoding, stored inline in the slots
+/// of a `FileList`.
+pub trait FixedCodec: Sized {
+    /// Number of bytes of an encoded element.
+    const SIZE: usize;
+
+    /// Writes the element into `bytes`, which is `SIZE` bytes long.
+    fn encode(&self, bytes: &mut [u8]);
+
+    /// Reads back an element written by `encode`, or returns `None` if
+    /// `bytes` is not a valid encoding.
+    fn decode(bytes: &[u8]) -> Option<Self>;
+}
+
+/// The bytes a `FileList` is stored in, implemented for `File`.
+///
+/// Every method takes `&self`, like the positioned reads and writes of a
+/// file. Implementations must report a failed write as an error rather than
+/// write only part of `buffer` silently.
+pub trait FileStorage {
+    /// Reads exactly `buffer.len()` bytes starting at `offset`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `UnexpectedEof` error if the storage ends first, or any
+    /// other error of reading.
+    fn read_at(&self, offset: u64, buffer: &mut [u8]) -> io::Result<()>;
+
+    /// Writes all of `buffer` starting at `offset`, growing the storage if
+    /// needed.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error of writing.
+    fn write_at(&self, offset: u64, buffer: &[u8]) -> io::Result<()>;
+
+    /// Returns the number of bytes stored.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error of querying the storage.
+    fn size(&self) -> io::Result<u64>;
+
+    /// Truncates or extends the storage to `size` bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error of resizing.
+    fn set_size(&self, size: u64) -> io::Result<()>;
+
+    /// Returns once every write made so far is durable, so that no later
+    /// write can reach the disk before them.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error of flushing.
+    fn sync(&self) -> io::Result<()>;
+}
+
+/// An error returned by `FileList`, either from the underlying file or from
+/// validating its contents.
+#[derive(Debug)]
+pub enum FileListError {
+    Io(io::Error),
+    /// The file does not start with a file list header.
+    InvalidHeader,
+    /// The file was written by an unknown version of the format.
+    UnsupportedVersion(u8),
+    /// The file stores elements of another size than `T::SIZE`.
+    ElementSizeMismatch {
+        expected: u64,
+        found: u64,
+    },
+    /// A link does not point at the start of a slot inside the file.
+    InvalidOffset {
+        offset: u64,
+    },
+    /// A slot reached from the head is marked free, or a slot on the free
+    /// list is marked used.
+    InvalidSlotState {
+        offset: u64,
+    },
+    /// Some slots are neither reachable from the head nor on the free list.
+    LeakedSlots {
+        expected: u64,
+        found: u64,
+    },
+    /// The element stored at `offset` does not decode.
+    InvalidElement {
+        offset: u64,
+    },
+    /// The links between the stored nodes break an invariant of `Node<T>`.
+    Invariant(InvariantViolation),
+    /// An earlier edit failed part of the way through, so the list refuses
+    /// further edits until it is reopened.
+    Poisoned,
+}
+
+impl Display for FileListError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(error) => write!(f, "{error}"),
+            Self::InvalidHeader => write!(f, "file does not start with a file list header"),
+            Self::UnsupportedVersion(version) => {
+                write!(f, "unsupported file list version {version}")
+            }
+            Self::ElementSizeMismatch { expected, found } => {
+                write!(f, "elements are {found} bytes long instead of {expected}")
+            }
+            Self::InvalidOffset { offset } => write!(f, "offset {offset} is not a slot"),
+            Self::InvalidSlotState { offset } => {
+                write!(f, "slot at offset {offset} has the wrong state")
+            }
+            Self::LeakedSlots { expected, found } => {
+                write!(f, "{found} of {expected} slots are in use or free")
+            }
+            Self::InvalidElement { offset } => {
+                write!(f, "element at offset {offset} does not decode")
+            }
+            Self::Invariant(violation) => write!(f, "{violation}"),
+            Self::Poisoned => {
+                write!(f, "an earlier edit failed, reopen the list to undo it")
+            }
+        }
+    }
+}
+
+impl Error for FileListError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Io(error) => Some(error),
+            Self::Invariant(violation) => Some(violation),
+            _ => None,
+        }
+    }
+}
+
+/// The links stored in front of an element.
+struct Slot {
+    state: u8,
+    previous: u64,
+    next: u64,
+}
+
+/// A doubly linked list whose nodes live in slots of a file, linked by byte
+/// offsets instead of pointers.
+///
+/// Before an edit changes anything, it logs the header and the slot links it
+/// is about to overwrite and syncs the file. It then writes its changes,
+/// syncs again and completes by rewriting the header, which clears the log.
+/// Opening a file whose last edit was cut short, by the process or the
+/// system stopping, undoes that edit and reads back the list as it was
+/// before it. `commit` syncs the header of the last edit as well, so it is
+/// not undone either. Slots of popped elements are kept on a free list and
+/// reused by later pushes.
+///
+/// An edit that fails with an error restores the list in memory, but leaves
+/// the list poisoned: further edits return `FileListError::Poisoned` until
+/// the file is reopened, which undoes the failed edit.
+///
+/// Opening a file also validates the same invariants as
+/// `LinkedList::check_invariants` together with the free list, so damage that
+/// no log accounts for is reported instead of being read back.
+pub struct FileList<T, S = File> {
+    storage: S,
+    head: u64,
+    tail: u64,
+    free: u64,
+    len: u64,
+    slot_count: u64,
+    poisoned: bool,
+    _marker: PhantomData<T>,
+}
+
+/// A double-ended iterator over the elements of a `FileList`, reading each
+/// one from the file.
+pub struct FileIter<'a, T, S = File> {
+    list: &'a FileList<T, S>,
+    front: u64,
+    back: u64,
+    pub(super) remaining: u64,
+}
+
+fn u64_at(bytes: &[u8], offset: usize) -> u64 {
+    let mut word = [0; 8];
+
+    word.copy_from_slice(&bytes[offset..offset + 8]);
+
+    u64::from_le_bytes(word)
+}
+
+fn to_u64(value: usize) -> u64 {
+    u64::try_from(value).expect("sizes fit in u64")
+}
+
+fn put_u64s<I: IntoIterator<Item = u64>>(bytes: &mut [u8], words: I) {
+    for (word, chunk) in words.into_iter().zip(bytes.chunks_exact_mut(8)) {
+        chunk.copy_from_slice(&word.to_le_bytes());
+    }
+}
+
+impl<T: FixedCodec> FileList<T> {
+    /// Creates an empty list at `path`, replacing any existing file.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error of creating or writing the file.
+    pub fn create<P: AsRef<Path>>(path: P) -> Result<Self, FileListError> {
+        let file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(true)
+            .open(path)?;
+
+        Self::create_in(file)
+    }
+
+    /// Opens the list stored at `path`, undoing an edit that was cut short,
+    /// and validates it.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error of reading or writing the file, or the first
+    /// inconsistency found by `validate`.
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self, FileListError> {
+        Self::open_in(OpenOptions::new().read(true).write(true).open(path)?)
+    }
+}
+
+impl<T: FixedCodec, S: FileStorage> FileList<T, S> {
+    const SLOT_LEN: u64 = (SLOT_HEADER_LEN + T::SIZE) as u64;
+
+    /// Creates an empty list in `storage`, discarding its contents.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error of writing the storage.
+    pub fn create_in(storage: S) -> Result<Self, FileListError> {
+        storage.set_size(0)?;
+
+        let mut list = Self {
+            storage,
+            head: NONE,
+            tail: NONE,
+            free: NONE,
+            len: 0,
+            slot_count: 0,
+            poisoned: false,
+            _marker: PhantomData,
+        };
+
+        list.commit()?;
+
+        Ok(list)
+    }
+
+    /// Opens the list stored in `storage` like `open`.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error of reading or writing the storage, or the first
+    /// inconsistency found by `validate`.
+    pub fn open_in(storage: S) -> Result<Self, FileListError> {
+        let mut header = [0; HEADER_LEN + LOG_LEN];
+
+        storage.read_at(0, &mut header).map_err(|error| {
+            if error.kind() == io::ErrorKind::UnexpectedEof {
+                FileListError::InvalidHeader
+            } else {
+                FileListError::Io(error)
+            }
+        })?;
+
+        if header[..4] != MAGIC {
+            return Err(FileListError::InvalidHeader);
+        }
+
+        if header[4] != VERSION {
+            return Err(FileListError::UnsupportedVersion(header[4]));
+        }
+
+        let element_size = u64_at(&header, 8);
+
+        if element_size != to_u64(T::SIZE) {
+            return Err(FileListError::ElementSizeMismatch {
+                expected: to_u64(T::SIZE),
+                found: element_size,
+            });
+        }
+
+        let slots_len = storage.size()? - FIRST_SLOT;
+        let mut list = Self {
+            storage,
+            head: u64_at(&header, 16),
+            tail: u64_at(&header, 24),
+            free: u64_at(&header, 32),
+            len: u64_at(&header, 40),
+            slot_count: slots_len / Self::SLOT_LEN,
+            poisoned: false,
+            _marker: PhantomData,
+        };
+        let log = &header[HEADER_LEN..];
+
+        match log[LOG_LEN - 1] {
+            EDIT_DONE if !slots_len.is_multiple_of(Self::SLOT_LEN) => {
+                return Err(FileListError::InvalidOffset {
+                    offset: FIRST_SLOT + slots_len - slots_len % Self::SLOT_LEN,
+                });
+            }
+            EDIT_DONE => {}
+            EDIT_PENDING => list.undo_edit(log)?,
+            _ => return Err(FileListError::InvalidHeader),
+        }
+
+        list.validate()?;
+
+        Ok(list)
+    }
+
+    #[must_use]
+    pub const fn len(&self) -> u64 {
+        self.len
+    }
+
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    const fn is_slot(&self, offset: u64) -> bool {
+        offset >= FIRST_SLOT
+            && (offset - FIRST_SLOT).is_multiple_of(Self::SLOT_LEN)
+            && (offset - FIRST_SLOT) / Self::SLOT_LEN < self.slot_count
+    }
+
+    fn read_slot(&self, offset: u64) -> Result<Slot, FileListError> {
+        if !self.is_slot(offset) {
+            return Err(FileListError::InvalidOffset { offset });
+        }
+
+        let mut bytes = [0; SLOT_HEADER_LEN];
+
+        self.storage.read_at(offset, &mut bytes)?;
+
+        Ok(Slot {
+            state: bytes[0],
+            previous: u64_at(&bytes, 1),
+            next: u64_at(&bytes, 9),
+        })
+    }
+
+    fn read_element(&self, offset: u64) -> Result<T, FileListError> {
+        let mut bytes = vec![0; T::SIZE];
+
+        self.storage
+            .read_at(offset + to_u64(SLOT_HEADER_LEN), &mut bytes)?;
+
+        T::decode(&bytes).ok_or(FileListError::InvalidElement { offset })
+    }
+
+    fn write_links(&self, offset: u64, previous: u64, next: u64) -> io::Result<()> {
+        self.storage.write_at(offset + 1, &previous.to_le_bytes())?;
+        self.storage.write_at(offset + 9, &next.to_le_bytes())
+    }
+
+    /// Writes the state and links of a slot, and its element if there is one.
+    /// Without an element, the bytes of the previous one are left in place.
+    fn write_slot(&self, offset: u64, slot: &Slot, element: Option<&T>) -> io::Result<()> {
+        let mut bytes = vec![0; SLOT_HEADER_LEN + T::SIZE];
+
+        bytes[0] = slot.state;
+        put_u64s(&mut bytes[1..SLOT_HEADER_LEN], [slot.previous, slot.next]);
+
+        match element {
+            Some(element) => element.encode(&mut bytes[SLOT_HEADER_LEN..]),
+            None => bytes.truncate(SLOT_HEADER_LEN),
+        }
+
+        self.storage.write_at(offset, &bytes)
+    }
+
+    /// Writes the header together with an empty log, which completes the
+    /// edit in progress.
+    fn write_header(&self) -> io::Result<()> {
+        let mut header = [0; HEADER_LEN + LOG_LEN];
+
+        header[..4].copy_from_slice(&MAGIC);
+        header[4] = VERSION;
+        put_u64s(
+            &mut header[8..HEADER_LEN],
+            [to_u64(T::SIZE), self.head, self.tail, self.free, self.len],
+        );
+
+        self.storage.write_at(0, &header)
+    }
+
+    /// Logs the header and the slot headers at `offsets` before an edit
+    /// overwrites them. `NONE` offsets are skipped.
+    fn log_edit(&self, offsets: [u64; 2]) -> Result<(), FileListError> {
+        let mut log = [0; LOG_LEN];
+
+        put_u64s(
+            &mut log,
+            [self.slot_count, self.head, self.tail, self.free, self.len],
+        );
+
+        for (offset, entry) in offsets
+            .into_iter()
+            .zip(log[40..].chunks_exact_mut(LOG_SLOT_LEN))
+        {
+            if offset == NONE {
+                continue;
+            }
+
+            if !self.is_slot(offset) {
+                return Err(FileListError::InvalidOffset { offset });
+            }
+
+            put_u64s(entry, [offset]);
+            self.storage.read_at(offset, &mut entry[8..])?;
+        }
+
+        log[LOG_LEN - 1] = EDIT_PENDING;
+        self.storage.write_at(LOG_OFFSET, &log)?;
+        self.storage.sync()?;
+
+        Ok(())
+    }
+
+    /// Restores the slot headers and the header recorded by `log_edit`, drops
+    /// any slot the edit appended and clears the log.
+    fn undo_edit(&mut self, log: &[u8]) -> Result<(), FileListError> {
+        self.slot_count = u64_at(log, 0);
+        self.head = u64_at(log, 8);
+        self.tail = u64_at(log, 16);
+        self.free = u64_at(log, 24);
+        self.len = u64_at(log, 32);
+
+        for entry in log[40..LOG_LEN - 1].chunks_exact(LOG_SLOT_LEN) {
+            let offset = u64_at(entry, 0);
+
+            if offset == NONE {
+                continue;
+            }
+
+            if !self.is_slot(offset) {
+                return Err(FileListError::InvalidOffset { offset });
+            }
+
+            self.storage.write_at(offset, &entry[8..])?;
+        }
+
+        self.storage
+            .set_size(FIRST_SLOT + self.slot_count * Self::SLOT_LEN)?;
+        self.commit()
+    }
+
+    /// Writes `element` into a free slot, or into a new one at the end of the
+    /// file, and returns its offset.
+    fn allocate_slot(
+        &mut self,
+        element: &T,
+        previous: u64,
+        next: u64,
+    ) -> Result<u64, FileListError> {
+        let offset = if self.free == NONE {
+            self.slot_count += 1;
+
+            FIRST_SLOT + (self.slot_count - 1) * Self::SLOT_LEN
+        } else {
+            let offset = self.free;
+
+            self.free = self.read_slot(offset)?.next;
+
+            offset
+        };
+
+        let slot = Slot {
+            state: SLOT_USED,
+            previous,
+            next,
+        };
+
+        self.write_slot(offset, &slot, Some(element))?;
+
+        Ok(offset)
+    }
+
+    /// Moves the slot at `offset` onto the free list, leaving its element in
+    /// place.
+    fn release_slot(&mut self, offset: u64) -> io::Result<()> {
+        let slot = Slot {
+            state: SLOT_FREE,
+            previous: NONE,
+            next: self.free,
+        };
+
+        self.write_slot(offset, &slot, None)?;
+        self.free = offset;
+
+        Ok(())
+    }
+
+    /// Adds `element` in front of the head.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error of reading or writing the file, or a `Poisoned`
+    /// error after an earlier edit failed.
+    pub fn push_front(&mut self, element: &T) -> Result<(), FileListError> {
+        self.edit(|list| list.link_front(element))
+    }
+
+    /// Adds `element` after the tail.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error of reading or writing the file, or a `Poisoned`
+    /// error after an earlier edit failed.
+    pub fn push_back(&mut self, element: &T) -> Result<(), FileListError> {
+        self.edit(|list| list.link_back(element))
+    }
+
+    /// Removes the head and returns its element.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error of reading or writing the file, an `InvalidElement`
+    /// error if the element does not decode, or a `Poisoned` error after an
+    /// earlier edit failed.
+    pub fn pop_front(&mut self) -> Result<Option<T>, FileListError> {
+        self.edit(Self::unlink_front)
+    }
+
+    /// Removes the tail and returns its element.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error of reading or writing the file, an `InvalidElement`
+    /// error if the element does not decode, or a `Poisoned` error after an
+    /// earlier edit failed.
+    pub fn pop_back(&mut self) -> Result<Option<T>, FileListError> {
+        self.edit(Self::unlink_back)
+    }
+
+    /// Runs `edit`, restoring the header in memory if it fails. The file may
+    /// then hold part of the edit next to its pending log, so the list is
+    /// poisoned: another edit would overwrite that log, and a commit would
+    /// clear it.
+    fn edit<R, F>(&mut self, edit: F) -> Result<R, FileListError>
+    where
+        F: FnOnce(&mut Self) -> Result<R, FileListError>,
+    {
+        if self.poisoned {
+            return Err(FileListError::Poisoned);
+        }
+
+        let header = (self.head, self.tail, self.free, self.len, self.slot_count);
+        let result = edit(self);
+
+        if result.is_err() {
+            (self.head, self.tail, self.free, self.len, self.slot_count) = header;
+            self.poisoned = true;
+        }
+
+        result
+    }
+
+    /// Syncs the changes of an edit before writing the header, so the log is
+    /// only cleared once they are durable.
+    fn finish_edit(&self) -> io::Result<()> {
+        self.storage.sync()?;
+        self.write_header()
+    }
+
+    /// Links `element` in front of the head.
+    fn link_front(&mut self, element: &T) -> Result<(), FileListError> {
+        self.log_edit([self.head, self.free])?;
+
+        let offset = self.allocate_slot(element, NONE, self.head)?;
+
+        if self.head == NONE {
+            self.tail = offset;
+        } else {
+            let head = self.read_slot(self.head)?;
+
+            self.write_links(self.head, offset, head.next)?;
+        }
+
+        self.head = offset;
+        self.len += 1;
+        self.finish_edit()?;
+
+        Ok(())
+    }
+
+    /// Links `element` after the tail.
+    fn link_back(&mut self, element: &T) -> Result<(), FileListError> {
+        self.log_edit([self.tail, self.free])?;
+
+        let offset = self.allocate_slot(element, self.tail, NONE)?;
+
+        if self.tail == NONE {
+            self.head = offset;
+        } else {
+            let tail = self.read_slot(self.tail)?;
+
+            self.write_links(self.tail, tail.previous, offset)?;
+        }
+
+        self.tail = offset;
+        self.len += 1;
+        self.finish_edit()?;
+
+        Ok(())
+    }
+
+    /// Unlinks the head and frees its slot.
+    fn unlink_front(&mut self) -> Result<Option<T>, FileListError> {
+        if self.head == NONE {
+            return Ok(None);
+        }
+
+        let old_head = self.head;
+        let element = self.read_element(old_head)?;
+        let next = self.read_slot(old_head)?.next;
+
+        self.log_edit([old_head, next])?;
+
+        if next == NONE {
+            self.tail = NONE;
+        } else {
+            let new_head = self.read_slot(next)?;
+
+            self.write_links(next, NONE, new_head.next)?;
+        }
+
+        self.head = next;
+        self.len -= 1;
+
+        self.release_slot(old_head)?;
+        self.finish_edit()?;
+
+        Ok(Some(element))
+    }
+
+    /// Unlinks the tail and frees its slot.
+    fn unlink_back(&mut self) -> Result<Option<T>, FileListError> {
+        if self.tail == NONE {
+            return Ok(None);
+        }
+
+        let old_tail = self.tail;
+        let element = self.read_element(old_tail)?;
+        let previous = self.read_slot(old_tail)?.previous;
+
+        self.log_edit([old_tail, previous])?;
+
+        if previous == NONE {
+            self.head = NONE;
+        } else {
+            let new_tail = self.read_slot(previous)?;
+
+            self.write_links(previous, new_tail.previous, NONE)?;
+        }
+
+        self.tail = previous;
+        self.len -= 1;
+
+        self.release_slot(old_tail)?;
+        self.finish_edit()?;
+
+        Ok(Some(element))
+    }
+
+    /// # Errors
+    ///
+    /// Returns any error of reading the file, or an `InvalidElement` error
+    /// if the element does not decode.
+    pub fn front(&self) -> Result<Option<T>, FileListError> {
+        if self.head == NONE {
+            return Ok(None);
+        }
+
+        self.read_element(self.head).map(Some)
+    }
+
+    /// # Errors
+    ///
+    /// Returns any error of reading the file, or an `InvalidElement` error
+    /// if the element does not decode.
+    pub fn back(&self) -> Result<Option<T>, FileListError> {
+        if self.tail == NONE {
+            return Ok(None);
+        }
+
+        self.read_element(self.tail).map(Some)
+    }
+
+    #[must_use]
+    pub const fn iter(&self) -> FileIter<'_, T, S> {
+        FileIter {
+            list: self,
+            front: self.head,
+            back: self.tail,
+            remaining: self.len,
+        }
+    }
+
+    /// Writes the header and flushes every edit made so far to disk.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error of writing or syncing the file, or a `Poisoned`
+    /// error after an edit failed.
+    pub fn commit(&mut self) -> Result<(), FileListError> {
+        if self.poisoned {
+            return Err(FileListError::Poisoned);
+        }
+
+        self.write_header()?;
+        self.storage.sync()?;
+
+        Ok(())
+    }
+
+    /// Walks the nodes from the head and the free list, checking the same
+    /// invariants as `LinkedList::check_invariants` and that every slot of
+    /// the file is either in the list or free.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first inconsistency found, or any error of reading the
+    /// file.
+    pub fn validate(&self) -> Result<(), FileListError> {
+        if self.head != NONE && self.read_slot(self.head)?.previous != NONE {
+            return Err(FileListError::Invariant(
+                InvariantViolation::HeadHasPrevious,
+            ));
+        }
+
+        if self.tail != NONE && self.read_slot(self.tail)?.next != NONE {
+            return Err(FileListError::Invariant(InvariantViolation::TailHasNext));
+        }
+
+        let mut found: u64 = 0;
+        let mut previous_offset = NONE;
+        let mut current_offset = self.head;
+
+        while current_offset != NONE {
+            if found == self.slot_count {
+                return Err(FileListError::Invariant(InvariantViolation::Cycle));
+            }
+
+            let slot = self.read_slot(current_offset)?;
+
+            if slot.state != SLOT_USED {
+                return Err(FileListError::InvalidSlotState {
+                    offset: current_offset,
+                });
+            }
+
+            if slot.previous != previous_offset {
+                return Err(FileListError::Invariant(
+                    InvariantViolation::BrokenBackLink {
+                        index: usize::try_from(found).unwrap_or(usize::MAX),
+                    },
+                ));
+            }
+
+            previous_offset = current_offset;
+            current_offset = slot.next;
+            found += 1;
+        }
+
+        if previous_offset != self.tail {
+            return Err(FileListError::Invariant(InvariantViolation::TailMismatch));
+        }
+
+        if found != self.len {
+            return Err(FileListError::Invariant(InvariantViolation::SizeMismatch {
+                expected: usize::try_from(self.len).unwrap_or(usize::MAX),
+                found: usize::try_from(found).unwrap_or(usize::MAX),
+            }));
+        }
+
+        let mut current_offset = self.free;
+
+        while current_offset != NONE {
+            if found == self.slot_count {
+                return Err(FileListError::Invariant(InvariantViolation::Cycle));
+            }
+
+            let slot = self.read_slot(current_offset)?;
+
+            if slot.state != SLOT_FREE {
+                return Err(FileListError::InvalidSlotState {
+                    offset: current_offset,
+                });
+            }
+
+            current_offset = slot.next;
+            found += 1;
+        }
+
+        if found != self.slot_count {
+            return Err(FileListError::LeakedSlots {
+                expected: self.slot_count,
+                found,
+            });
+        }
+
+        Ok(())
+    }
+}
+
+impl<T: FixedCodec, S: FileStorage> FileIter<'_, T, S> {
+    /// Reads the element at `offset`, passing its links to `advance`. After
+    /// an error the iteration ends.
+    fn read(
+        &mut self,
+        offset: u64,
+        advance: fn(&mut Self, Slot),
+    ) -> Option<Result<T, FileListError>> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        let node = self
+            .list
+            .read_slot(offset)
+            .and_then(|slot| Ok((self.list.read_element(offset)?, slot)));
+
+        match node {
+            Ok((element, slot)) => {
+                self.remaining -= 1;
+                advance(self, slot);
+
+                Some(Ok(element))
+            }
+            Err(error) => {
+                self.remaining = 0;
+
+                Some(Err(error))
+            }
+        }
+    }
+
+    pub(super) fn read_front(&mut self) -> Option<Result<T, FileListError>> {
+        self.read(self.front, |iter, slot| iter.front = slot.next)
+    }
+
+    pub(super) fn read_back(&mut self) -> Option<Result<T, FileListError>> {
+        self.read(self.back, |iter, slot| iter.back = slot.previous)
+    }
+}
@@ -0,0 +1,119 @@
+use std::collections::VecDeque;
+
+use super::LinkedList;
+
+/// Decodes `data` into a sequence of list operations, runs them against both a
+/// `LinkedList` and a `VecDeque` model, and panics on the first divergence.
+///
+/// Every byte selects an operation; operations that take an argument consume
+/// the following byte as well, defaulting to zero at the end of the input.
+/// Any input is valid, which makes this suitable as a fuzzing target.
+///
+/// # Panics
+///
+/// Panics if the list diverges from the model or breaks one of its invariants.
+pub fn fuzz_ops(data: &[u8]) {
+    let mut list = LinkedList::new();
+    let mut model = VecDeque::new();
+    let mut bytes = data.iter().copied();
+
+    while let Some(opcode) = bytes.next() {
+        let mut operand = || bytes.next().unwrap_or(0);
+
+        match opcode % 13 {
+            0 => {
+                let element = operand();
+
+                list.push_front(element);
+                model.push_front(element);
+            }
+            1 => {
+                let element = operand();
+
+                list.push_back(element);
+                model.push_back(element);
+            }
+            2 => assert_eq!(list.pop_front(), model.pop_front(), "pop_front"),
+            3 => assert_eq!(list.pop_back(), model.pop_back(), "pop_back"),
+            4 => {
+                let divisor = operand() % 4 + 1;
+
+                list.retain(|element| element % divisor == 0);
+                model.retain(|element| element % divisor == 0);
+            }
+            5 => {
+                list.reverse();
+                model.make_contiguous().reverse();
+            }
+            6 => {
+                let mid = model.len().div_ceil(2);
+
+                model.truncate(mid);
+                list = list.split().0;
+            }
+            7 => {
+                let mid = model.len().div_ceil(2);
+
+                model.drain(..mid);
+                list = list.split().1;
+            }
+            8 => {
+                list.clear();
+                model.clear();
+            }
+            9 => {
+                let amount = operand();
+
+                for element in &mut list {
+                    *element = element.wrapping_add(amount);
+                }
+
+                for element in &mut model {
+                    *element = element.wrapping_add(amount);
+                }
+            }
+            10 => {
+                let element = operand();
+
+                if let (Some(front), Some(model_front)) = (list.front_mut(), model.front_mut()) {
+                    *front = element;
+                    *model_front = element;
+                }
+
+                if let (Some(back), Some(model_back)) = (list.back_mut(), model.back_mut()) {
+                    *back = element;
+                    *model_back = element;
+                }
+            }
+            11 => {
+                let element = operand();
+
+                assert_eq!(
+                    list.contains(&element),
+                    model.contains(&element),
+                    "contains"
+                );
+            }
+            _ => {
+                let taken = usize::from(operand());
+                let mut iter = list.into_iter();
+
+                for _ in 0..taken {
+                    assert_eq!(iter.next(), model.pop_front(), "into_iter");
+                }
+
+                list = iter.list;
+            }
+        }
+
+        check(&list, &model);
+    }
+}
+
+fn check(list: &LinkedList<u8>, model: &VecDeque<u8>) {
+    assert_eq!(list.check_invariants(), Ok(()));
+    assert_eq!(list.len(), model.len(), "len");
+    assert_eq!(list.front(), model.front(), "front");
+    assert_eq!(list.back(), model.back(), "back");
+    assert!(list.iter().eq(model.iter()), "elements");
+}
@@ -0,0 +1,43 @@
+use std::{
+    error::Error,
+    fmt::{self, Display},
+};
+
+/// A structural inconsistency found by `LinkedList::check_invariants`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InvariantViolation {
+    /// The head node has a `previous` link.
+    HeadHasPrevious,
+    /// The tail node has a `next` link.
+    TailHasNext,
+    /// The node at `index` does not point back to the node before it.
+    BrokenBackLink { index: usize },
+    /// Following `next` links from the head never reaches the end.
+    Cycle,
+    /// The last node reached from the head is not the tail.
+    TailMismatch,
+    /// The number of reachable nodes differs from the stored size.
+    SizeMismatch { expected: usize, found: usize },
+}
+
+impl Display for InvariantViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::HeadHasPrevious => write!(f, "head node has a previous link"),
+            Self::TailHasNext => write!(f, "tail node has a next link"),
+            Self::BrokenBackLink { index } => {
+                write!(
+                    f,
+                    "node at index {index} does not link back to its predecessor"
+                )
+            }
+            Self::Cycle => write!(f, "next links form a cycle"),
+            Self::TailMismatch => write!(f, "last reachable node is not the tail"),
+            Self::SizeMismatch { expected, found } => {
+                write!(f, "size is {expected} but {found} nodes are reachable")
+            }
+        }
+    }
+}
+
+impl Error for InvariantViolation {}
@@ -0,0 +1,424 @@
+use std::{
+    cell::{Cell, RefCell},
+    error::Error,
+    fmt::{self, Display},
+    mem,
+    str::FromStr,
+};
+
+use super::LinkedList;
+
+/// A single mutating call recorded by `RecordedList`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum JournalEntry<T> {
+    PushFront(T),
+    PushBack(T),
+    PopFront,
+    PopBack,
+    /// Whether each element, from front to back, was kept.
+    Retain(Vec<bool>),
+    Reverse,
+    Clear,
+    /// Only the front half was kept after a split.
+    KeepFront,
+    /// Only the back half was kept after a split.
+    KeepBack,
+}
+
+/// The sequence of mutating calls applied to a `RecordedList`.
+///
+/// The text format written by `Display` and read by `FromStr` holds one
+/// entry per line, such as `push_back 5`, `pop_front` or `retain 1101`.
+/// Elements take the rest of the line with `\`, newlines and carriage
+/// returns escaped.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Journal<T> {
+    pub entries: Vec<JournalEntry<T>>,
+}
+
+impl<T> Journal<T> {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { entries: vec![] }
+    }
+
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+/// Collects the outcomes of a `RecordedList::retain` and journals them once
+/// dropped, even if the predicate panicked.
+pub struct RetainOutcome<'a, T> {
+    journal: &'a mut Journal<T>,
+    kept: RefCell<Vec<bool>>,
+    len: usize,
+}
+
+impl<'a, T> RetainOutcome<'a, T> {
+    fn new(journal: &'a mut Journal<T>, len: usize) -> Self {
+        Self {
+            journal,
+            kept: RefCell::new(Vec::with_capacity(len)),
+            len,
+        }
+    }
+
+    fn record(&self, keep: bool) -> bool {
+        self.kept.borrow_mut().push(keep);
+
+        keep
+    }
+
+    /// Journals the outcomes, counting the elements the predicate never saw
+    /// as kept.
+    pub(super) fn finish(&mut self) {
+        let mut kept = self.kept.take();
+
+        kept.resize(self.len, true);
+        self.journal.entries.push(JournalEntry::Retain(kept));
+    }
+}
+
+/// A `LinkedList` that records every mutating call in a `Journal`.
+pub struct RecordedList<T> {
+    list: LinkedList<T>,
+    journal: Journal<T>,
+}
+
+impl<T> RecordedList<T> {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            list: LinkedList::new(),
+            journal: Journal::new(),
+        }
+    }
+
+    #[must_use]
+    pub const fn list(&self) -> &LinkedList<T> {
+        &self.list
+    }
+
+    #[must_use]
+    pub const fn journal(&self) -> &Journal<T> {
+        &self.journal
+    }
+
+    #[must_use]
+    pub fn into_parts(self) -> (LinkedList<T>, Journal<T>) {
+        (self.list, self.journal)
+    }
+
+    pub fn pop_front(&mut self) -> Option<T> {
+        self.journal.entries.push(JournalEntry::PopFront);
+        self.list.pop_front()
+    }
+
+    pub fn pop_back(&mut self) -> Option<T> {
+        self.journal.entries.push(JournalEntry::PopBack);
+        self.list.pop_back()
+    }
+
+    /// Retains the elements matching `predicate`, recording which elements
+    /// were kept rather than the predicate itself.
+    ///
+    /// If `predicate` panics, the elements it did not get to stay in the list
+    /// and are recorded as kept, so the journal still replays to the list.
+    pub fn retain<F>(&mut self, predicate: F)
+    where
+        F: Fn(&T) -> bool,
+    {
+        let outcome = RetainOutcome::new(&mut self.journal, self.list.len());
+
+        self.list
+            .retain(|element| outcome.record(predicate(element)));
+    }
+
+    pub fn reverse(&mut self) {
+        self.journal.entries.push(JournalEntry::Reverse);
+        self.list.reverse();
+    }
+
+    pub fn clear(&mut self) {
+        self.journal.entries.push(JournalEntry::Clear);
+        self.list.clear();
+    }
+}
+
+impl<T: Clone> RecordedList<T> {
+    pub fn push_front(&mut self, element: T) {
+        self.journal
+            .entries
+            .push(JournalEntry::PushFront(element.clone()));
+        self.list.push_front(element);
+    }
+
+    pub fn push_back(&mut self, element: T) {
+        self.journal
+            .entries
+            .push(JournalEntry::PushBack(element.clone()));
+        self.list.push_back(element);
+    }
+
+    /// Splits the list like `LinkedList::split`, keeping the front half and
+    /// returning the back half.
+    pub fn split_off_back(&mut self) -> LinkedList<T> {
+        self.journal.entries.push(JournalEntry::KeepFront);
+
+        let (front, back) = mem::take(&mut self.list).split();
+
+        self.list = front;
+
+        back
+    }
+
+    /// Splits the list like `LinkedList::split`, keeping the back half and
+    /// returning the front half.
+    pub fn split_off_front(&mut self) -> LinkedList<T> {
+        self.journal.entries.push(JournalEntry::KeepBack);
+
+        let (front, back) = mem::take(&mut self.list).split();
+
+        self.list = back;
+
+        front
+    }
+}
+
+/// An error returned when a journal cannot be replayed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReplayError {
+    /// Index of the `Retain` entry that failed.
+    pub index: usize,
+    /// Number of elements in the list when the entry was replayed.
+    pub expected: usize,
+    /// Number of outcomes recorded in the entry.
+    pub found: usize,
+}
+
+impl Display for ReplayError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "retain entry {} records {} outcomes for a list of {} elements",
+            self.index, self.found, self.expected
+        )
+    }
+}
+
+impl Error for ReplayError {}
+
+/// Rebuilds the list that `journal` was recorded from, starting from an
+/// empty list.
+///
+/// # Errors
+///
+/// Returns a `ReplayError` if a `Retain` entry does not cover exactly the
+/// elements of the list at that point, which only happens for journals that
+/// were not produced by a `RecordedList`.
+pub fn replay<T: Clone>(journal: &Journal<T>) -> Result<LinkedList<T>, ReplayError> {
+    let mut list = LinkedList::new();
+
+    for (index, entry) in journal.entries.iter().enumerate() {
+        match entry {
+            JournalEntry::PushFront(element) => list.push_front(element.clone()),
+            JournalEntry::PushBack(element) => list.push_back(element.clone()),
+            JournalEntry::PopFront => drop(list.pop_front()),
+            JournalEntry::PopBack => drop(list.pop_back()),
+            JournalEntry::Retain(outcome) => {
+                if outcome.len() != list.len() {
+                    return Err(ReplayError {
+                        index,
+                        expected: list.len(),
+                        found: outcome.len(),
+                    });
+                }
+
+                let position = Cell::new(0);
+
+                list.retain(|_| {
+                    let keep = outcome[position.get()];
+
+                    position.set(position.get() + 1);
+
+                    keep
+                });
+            }
+            JournalEntry::Reverse => list.reverse(),
+            JournalEntry::Clear => list.clear(),
+            JournalEntry::KeepFront => list = list.split().0,
+            JournalEntry::KeepBack => list = list.split().1,
+        }
+    }
+
+    Ok(list)
+}
+
+/// An error returned when parsing a `Journal` from its text format.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseJournalError<E> {
+    /// One-based number of the offending line.
+    pub line: usize,
+    pub kind: ParseJournalErrorKind<E>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseJournalErrorKind<E> {
+    /// The line does not start with a known entry name.
+    UnknownEntry,
+    /// An entry that takes no argument is followed by one.
+    UnexpectedArgument,
+    /// A `retain` outcome contains something other than `0` and `1`.
+    InvalidOutcome,
+    /// An element contains an unknown or unfinished escape sequence.
+    InvalidEscape,
+    /// The element failed to parse.
+    Element(E),
+}
+
+impl<E: Display> Display for ParseJournalError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.kind {
+            ParseJournalErrorKind::UnknownEntry => write!(f, "unknown entry")?,
+            ParseJournalErrorKind::UnexpectedArgument => write!(f, "unexpected argument")?,
+            ParseJournalErrorKind::InvalidOutcome => {
+                write!(f, "retain outcome must consist of `0` and `1`")?;
+            }
+            ParseJournalErrorKind::InvalidEscape => write!(f, "invalid escape sequence")?,
+            ParseJournalErrorKind::Element(error) => write!(f, "invalid element: {error}")?,
+        }
+
+        write!(f, " on line {}", self.line)
+    }
+}
+
+impl<E: Error + 'static> Error for ParseJournalError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match &self.kind {
+            ParseJournalErrorKind::Element(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+pub fn write_entry<T: Display>(f: &mut fmt::Formatter<'_>, entry: &JournalEntry<T>) -> fmt::Result {
+    let write_element = |f: &mut fmt::Formatter<'_>, name, element: &T| {
+        write!(f, "{name} ")?;
+
+        for c in element.to_string().chars() {
+            match c {
+                '\\' => write!(f, "\\\\")?,
+                '\n' => write!(f, "\\n")?,
+                '\r' => write!(f, "\\r")?,
+                c => write!(f, "{c}")?,
+            }
+        }
+
+        Ok(())
+    };
+
+    match entry {
+        JournalEntry::PushFront(element) => write_element(f, "push_front", element)?,
+        JournalEntry::PushBack(element) => write_element(f, "push_back", element)?,
+        JournalEntry::PopFront => write!(f, "pop_front")?,
+        JournalEntry::PopBack => write!(f, "pop_back")?,
+        JournalEntry::Retain(outcome) => {
+            write!(f, "retain ")?;
+
+            for keep in outcome {
+                write!(f, "{}", u8::from(*keep))?;
+            }
+        }
+        JournalEntry::Reverse => write!(f, "reverse")?,
+        JournalEntry::Clear => write!(f, "clear")?,
+        JournalEntry::KeepFront => write!(f, "keep_front")?,
+        JournalEntry::KeepBack => write!(f, "keep_back")?,
+    }
+
+    writeln!(f)
+}
+
+fn unescape<E>(text: &str) -> Result<String, ParseJournalErrorKind<E>> {
+    let mut unescaped = String::with_capacity(text.len());
+    let mut chars = text.chars();
+
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            unescaped.push(c);
+
+            continue;
+        }
+
+        match chars.next() {
+            Some('\\') => unescaped.push('\\'),
+            Some('n') => unescaped.push('\n'),
+            Some('r') => unescaped.push('\r'),
+            _ => return Err(ParseJournalErrorKind::InvalidEscape),
+        }
+    }
+
+    Ok(unescaped)
+}
+
+fn parse_entry<T: FromStr>(line: &str) -> Result<JournalEntry<T>, ParseJournalErrorKind<T::Err>> {
+    let (name, argument) = line
+        .split_once(' ')
+        .map_or((line, None), |(name, argument)| (name, Some(argument)));
+    let element = || {
+        unescape(argument.unwrap_or_default())?
+            .parse()
+            .map_err(ParseJournalErrorKind::Element)
+    };
+    let bare = |entry| match argument {
+        Some(_) => Err(ParseJournalErrorKind::UnexpectedArgument),
+        None => Ok(entry),
+    };
+
+    match name {
+        "push_front" => element().map(JournalEntry::PushFront),
+        "push_back" => element().map(JournalEntry::PushBack),
+        "retain" => argument
+            .unwrap_or_default()
+            .chars()
+            .map(|c| match c {
+                '0' => Ok(false),
+                '1' => Ok(true),
+                _ => Err(ParseJournalErrorKind::InvalidOutcome),
+            })
+            .collect::<Result<_, _>>()
+            .map(JournalEntry::Retain),
+        "pop_front" => bare(JournalEntry::PopFront),
+        "pop_back" => bare(JournalEntry::PopBack),
+        "reverse" => bare(JournalEntry::Reverse),
+        "clear" => bare(JournalEntry::Clear),
+        "keep_front" => bare(JournalEntry::KeepFront),
+        "keep_back" => bare(JournalEntry::KeepBack),
+        _ => Err(ParseJournalErrorKind::UnknownEntry),
+    }
+}
+
+pub fn parse_journal<T: FromStr>(input: &str) -> Result<Journal<T>, ParseJournalError<T::Err>> {
+    let mut journal = Journal::new();
+
+    for (index, line) in input.lines().enumerate() {
+        if line.is_empty() {
+            continue;
+        }
+
+        let entry = parse_entry(line).map_err(|kind| ParseJournalError {
+            line: index + 1,
+            kind,
+        })?;
+
+        journal.entries.push(entry);
+    }
+
+    Ok(journal)
+}
@@ -2,12 +2,57 @@
 #![deny(clippy::pedantic)]
 #![deny(clippy::nursery)]
 
+mod binary;
+mod channel;
+mod concurrent_queue;
+mod cow_list;
+mod diagram;
+mod display;
+mod dot;
+mod external_sort;
+mod file_list;
+#[cfg(any(test, feature = "fuzzing"))]
+mod fuzzing;
+mod invariants;
+mod journal;
 mod list;
+mod locking_list;
 mod node;
 mod node_allocator;
+mod parallel;
+mod parse;
+mod persistent_list;
+mod sort;
 mod traits;
+mod transaction;
+mod unrolled_list;
+mod xor_list;
 
 #[cfg(test)]
 mod tests;
 
+pub use binary::{Decode, DecodeError, Encode};
+pub use channel::{
+    Receiver, RecvError, RecvTimeoutError, SendError, Sender, TryRecvError, TrySendError, channel,
+};
+pub use concurrent_queue::{ConcurrentQueue, QueueConsumer};
+pub use cow_list::CowList;
+pub use diagram::{DiagramOptions, DiagramTrace};
+pub use display::{DisplayOptions, DisplayWith};
+pub use dot::DotOptions;
+pub use external_sort::{ExternalSortIter, RunCodec};
+pub use file_list::{FileIter, FileList, FileListError, FileStorage, FixedCodec};
+#[cfg(feature = "fuzzing")]
+pub use fuzzing::fuzz_ops;
+pub use invariants::InvariantViolation;
+pub use journal::{
+    Journal, JournalEntry, ParseJournalError, ParseJournalErrorKind, RecordedList, ReplayError,
+    replay,
+};
 pub use list::LinkedList;
+pub use locking_list::LockingList;
+pub use parse::{ParseListError, ParseListErrorKind};
+pub use persistent_list::PersistentList;
+pub use transaction::Transaction;
+pub use unrolled_list::UnrolledLinkedList;
+pub use xor_list::XorLinkedList;
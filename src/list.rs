@@ -1,12 +1,24 @@
 use std::{
+    cmp::Ordering,
+    fmt::Display,
+    io::{self, Read, Write},
     marker::PhantomData,
     mem,
+    path::Path,
     ptr::{self, NonNull},
 };
 
 use super::{
+    binary::{self, Decode, DecodeError, Encode},
+    diagram::{self, DiagramOptions},
+    display::{DisplayOptions, DisplayWith},
+    dot::{self, DotOptions},
+    external_sort::{self, ExternalSortIter, RunCodec},
+    invariants::InvariantViolation,
     node::{Link, Node},
     node_allocator::{allocate_node, deallocate_node},
+    parallel,
+    transaction::Transaction,
 };
 
 pub struct ListIter<'a, T> {
@@ -23,6 +35,16 @@ pub struct ListIntoIter<T> {
     pub(super) list: LinkedList<T>,
 }
 
+/// Keeps popping nodes if an element's destructor panics, so that the rest of
+/// the chain is still freed while unwinding.
+struct ClearGuard<'a, T>(&'a mut LinkedList<T>);
+
+impl<T> Drop for ClearGuard<'_, T> {
+    fn drop(&mut self) {
+        while self.0.pop_front().is_some() {}
+    }
+}
+
 #[derive(Debug)]
 pub struct LinkedList<T> {
     pub(super) head: Link<T>,
@@ -55,6 +77,8 @@ impl<T> LinkedList<T> {
 
         self.head = Some(new_node_ptr);
         self.size += 1;
+
+        self.debug_check();
     }
 
     pub fn push_back(&mut self, element: T) {
@@ -72,6 +96,8 @@ impl<T> LinkedList<T> {
 
         self.tail = Some(new_node_ptr);
         self.size += 1;
+
+        self.debug_check();
     }
 
     pub fn pop_front(&mut self) -> Option<T> {
@@ -91,6 +117,8 @@ impl<T> LinkedList<T> {
 
         self.size -= 1;
 
+        self.debug_check();
+
         Some(popped_element)
     }
 
@@ -111,6 +139,8 @@ impl<T> LinkedList<T> {
 
         self.size -= 1;
 
+        self.debug_check();
+
         Some(popped_element)
     }
 
@@ -164,10 +194,19 @@ impl<T> LinkedList<T> {
     }
 
     pub fn clear(&mut self) {
-        while self.pop_front().is_some() {}
+        let guard = ClearGuard(self);
+
+        while guard.0.pop_front().is_some() {}
+
+        mem::forget(guard);
+
+        self.debug_check();
     }
 
-    pub const fn reverse(&mut self) {
+    // Not `const` so that the `debug-checks` feature can verify the result
+    // without changing the signature.
+    #[cfg_attr(not(feature = "debug-checks"), allow(clippy::missing_const_for_fn))]
+    pub fn reverse(&mut self) {
         if self.is_empty() || self.len() == 1 {
             return;
         }
@@ -186,6 +225,8 @@ impl<T> LinkedList<T> {
         }
 
         mem::swap(&mut self.head, &mut self.tail);
+
+        self.debug_check();
     }
 
     #[must_use]
@@ -212,11 +253,14 @@ impl<T> LinkedList<T> {
             index += 1;
         }
 
+        first_list.debug_check();
+        second_list.debug_check();
+
         (first_list, second_list)
     }
 
     fn remove_node(&mut self, node: NonNull<Node<T>>) {
-        unsafe {
+        let removed_element = unsafe {
             let previous_node = node.as_ref().previous;
             let next_node = node.as_ref().next;
 
@@ -232,10 +276,17 @@ impl<T> LinkedList<T> {
                 self.tail = previous_node;
             }
 
+            let removed_element = ptr::read(&raw const node.as_ref().element);
+
             deallocate_node(node);
-        }
+
+            removed_element
+        };
 
         self.size -= 1;
+
+        // Dropped last so that a panicking destructor leaves the list intact.
+        drop(removed_element);
     }
 
     pub fn retain<F>(&mut self, predicate: F)
@@ -253,6 +304,73 @@ impl<T> LinkedList<T> {
 
             current_node = next_node;
         }
+
+        self.debug_check();
+    }
+
+    /// Applies `f` to every element, splitting the list into segments that
+    /// are processed on scoped threads.
+    ///
+    /// # Panics
+    ///
+    /// Re-raises a panic of `f` once every segment has been linked back.
+    pub fn par_for_each_mut<F>(&mut self, f: F)
+    where
+        T: Send,
+        F: Fn(&mut T) + Sync,
+    {
+        parallel::for_each_segment(self, |segment| segment.iter_mut().for_each(&f));
+
+        self.debug_check();
+    }
+
+    /// Maps every element on scoped threads, keeping the order of the list.
+    ///
+    /// # Panics
+    ///
+    /// Re-raises a panic of `f`.
+    pub fn par_map_into_vec<U, F>(&self, f: F) -> Vec<U>
+    where
+        T: Sync,
+        U: Send,
+        F: Fn(&T) -> U + Sync,
+    {
+        parallel::map_into_vec(self, f)
+    }
+
+    /// Retains the elements matching `predicate`, filtering segments of the
+    /// list on scoped threads and relinking what is left in order.
+    ///
+    /// # Panics
+    ///
+    /// Re-raises a panic of `predicate` once every segment has been linked
+    /// back.
+    pub fn par_retain<F>(&mut self, predicate: F)
+    where
+        T: Send,
+        F: Fn(&T) -> bool + Sync,
+    {
+        parallel::for_each_segment(self, |segment| segment.retain(&predicate));
+
+        self.debug_check();
+    }
+
+    /// Sorts the list stably by `compare` by relinking its nodes. Runs of the
+    /// list are sorted on scoped threads and then merged in parallel rounds;
+    /// apart from the threads nothing is allocated.
+    ///
+    /// # Panics
+    ///
+    /// Re-raises a panic of `compare` once every node has been linked back.
+    /// The order of the elements is unspecified afterwards.
+    pub fn par_sort_by<F>(&mut self, compare: F)
+    where
+        T: Send,
+        F: Fn(&T, &T) -> Ordering + Sync,
+    {
+        parallel::sort_by(self, compare);
+
+        self.debug_check();
     }
 
     #[must_use]
@@ -269,4 +387,261 @@ impl<T> LinkedList<T> {
             _marker: PhantomData,
         }
     }
+
+    /// Applies the edits made by `edits`, keeping them if it returns `Ok` and
+    /// restoring the original nodes and their order if it returns `Err` or
+    /// panics.
+    ///
+    /// Edits are recorded as an undo log of relinks, so the list is never
+    /// cloned. Removed elements are dropped once the transaction commits.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error produced by `edits`.
+    pub fn transaction<R, E, F>(&mut self, edits: F) -> Result<R, E>
+    where
+        F: FnOnce(&mut Transaction<'_, T>) -> Result<R, E>,
+    {
+        let mut transaction = Transaction::new(self);
+        let result = edits(&mut transaction);
+
+        transaction.committed = result.is_ok();
+        drop(transaction);
+
+        self.debug_check();
+
+        result
+    }
+
+    /// Sorts the elements of `source` stably by `compare` without holding
+    /// more than `run_size` of them in memory at once.
+    ///
+    /// Runs of `run_size` elements are sorted in a list and written through
+    /// `codec` to files in `temp_dir`, which are then k-way merged. If the
+    /// source fits in a single run, nothing is written to disk. The returned
+    /// iterator removes the run files as they are exhausted or when it is
+    /// dropped.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error of creating or writing a run file.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `run_size` is zero.
+    pub fn external_sort_iter_by<I, F, C>(
+        source: I,
+        compare: F,
+        run_size: usize,
+        temp_dir: &Path,
+        codec: C,
+    ) -> io::Result<ExternalSortIter<T, F, C>>
+    where
+        I: IntoIterator<Item = T>,
+        F: FnMut(&T, &T) -> Ordering,
+        C: RunCodec<T>,
+    {
+        external_sort::sort_runs(source, compare, run_size, temp_dir, codec)
+    }
+
+    /// Collects the result of `external_sort_iter_by` into a list.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error of writing or reading back a run file.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `run_size` is zero.
+    pub fn external_sort_by<I, F, C>(
+        source: I,
+        compare: F,
+        run_size: usize,
+        temp_dir: &Path,
+        codec: C,
+    ) -> io::Result<Self>
+    where
+        I: IntoIterator<Item = T>,
+        F: FnMut(&T, &T) -> Ordering,
+        C: RunCodec<T>,
+    {
+        let mut sorted = Self::new();
+
+        for element in Self::external_sort_iter_by(source, compare, run_size, temp_dir, codec)? {
+            sorted.push_back(element?);
+        }
+
+        Ok(sorted)
+    }
+
+    #[must_use]
+    pub const fn display_with<'a>(&'a self, options: DisplayOptions<'a>) -> DisplayWith<'a, T> {
+        DisplayWith {
+            list: self,
+            options,
+        }
+    }
+
+    /// Streams the list to `writer` in the binary list format: a magic
+    /// header, a version byte and the number of elements, followed by each
+    /// element encoded with `Encode`.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error produced by `writer`.
+    pub fn write_to<W: Write>(&self, writer: &mut W) -> io::Result<()>
+    where
+        T: Encode,
+    {
+        binary::write_list(self, writer)
+    }
+
+    /// Reads a list written by `write_to`, decoding one element at a time.
+    /// Input after the last element is left unread.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error produced by `reader`, or a `DecodeError` if the
+    /// header or an element is malformed.
+    pub fn read_from<R: Read>(reader: &mut R) -> Result<Self, DecodeError>
+    where
+        T: Decode,
+    {
+        binary::read_list(reader)
+    }
+
+    /// Renders the node graph in the Graphviz DOT language.
+    #[must_use]
+    pub fn to_dot(&self) -> String
+    where
+        T: Display,
+    {
+        let mut output = vec![];
+
+        // Writing to a `Vec<u8>` cannot fail.
+        let _ = self.write_dot(&mut output);
+
+        String::from_utf8_lossy(&output).into_owned()
+    }
+
+    /// Streams the node graph in the Graphviz DOT language to `writer`.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error produced by `writer`.
+    pub fn write_dot<W: Write>(&self, writer: &mut W) -> io::Result<()>
+    where
+        T: Display,
+    {
+        self.write_dot_with(writer, DotOptions::default())
+    }
+
+    /// Streams the node graph to `writer` using the given `options`.
+    ///
+    /// The graph follows the raw `previous` and `next` links, so links that
+    /// point at nodes not reachable from the head are drawn as such.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error produced by `writer`.
+    pub fn write_dot_with<W: Write>(&self, writer: &mut W, options: DotOptions) -> io::Result<()>
+    where
+        T: Display,
+    {
+        dot::write_dot(self, writer, options)
+    }
+
+    /// Draws the nodes as ASCII boxes with their links, wrapped to 80 columns.
+    #[must_use]
+    pub fn ascii_diagram(&self) -> String
+    where
+        T: Display,
+    {
+        self.ascii_diagram_with(DiagramOptions::default())
+    }
+
+    /// Draws the nodes as ASCII boxes laid out according to `options`.
+    ///
+    /// A link whose target does not point back is drawn as `-->` instead of
+    /// `<->`, and the drawing stops after `len` nodes if the links run on.
+    #[must_use]
+    pub fn ascii_diagram_with(&self, options: DiagramOptions) -> String
+    where
+        T: Display,
+    {
+        diagram::ascii_diagram(self, options)
+    }
+
+    /// Verifies the structural invariants of the list.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first `InvariantViolation` found while walking the nodes
+    /// from the head.
+    pub fn check_invariants(&self) -> Result<(), InvariantViolation> {
+        if let Some(head) = self.head
+            && unsafe { head.as_ref().previous }.is_some()
+        {
+            return Err(InvariantViolation::HeadHasPrevious);
+        }
+
+        if let Some(tail) = self.tail
+            && unsafe { tail.as_ref().next }.is_some()
+        {
+            return Err(InvariantViolation::TailHasNext);
+        }
+
+        let next_of = |link: Link<T>| link.and_then(|node| unsafe { node.as_ref().next });
+        let mut slow_node = self.head;
+        let mut fast_node = next_of(self.head);
+
+        while fast_node.is_some() {
+            if fast_node == slow_node {
+                return Err(InvariantViolation::Cycle);
+            }
+
+            slow_node = next_of(slow_node);
+            fast_node = next_of(next_of(fast_node));
+        }
+
+        let mut found: usize = 0;
+        let mut previous_node = None;
+        let mut current_node = self.head;
+
+        while let Some(node) = current_node {
+            let node_ref = unsafe { node.as_ref() };
+
+            if node_ref.previous != previous_node {
+                return Err(InvariantViolation::BrokenBackLink { index: found });
+            }
+
+            previous_node = current_node;
+            current_node = node_ref.next;
+            found += 1;
+        }
+
+        if previous_node != self.tail {
+            return Err(InvariantViolation::TailMismatch);
+        }
+
+        if found != self.size {
+            return Err(InvariantViolation::SizeMismatch {
+                expected: self.size,
+                found,
+            });
+        }
+
+        Ok(())
+    }
+
+    #[cfg(feature = "debug-checks")]
+    fn debug_check(&self) {
+        if let Err(violation) = self.check_invariants() {
+            panic!("linked list invariant violated: {violation}");
+        }
+    }
+
+    #[cfg(not(feature = "debug-checks"))]
+    #[allow(clippy::unused_self)]
+    const fn debug_check(&self) {}
 }
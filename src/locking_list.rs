@@ -0,0 +1,162 @@
+use std::{
+    cell::Cell,
+    ptr::{self, NonNull},
+    sync::{
+        Mutex, MutexGuard, PoisonError,
+        atomic::{AtomicUsize, Ordering},
+    },
+};
+
+use super::{
+    LinkedList,
+    node::LockingNode,
+    node_allocator::{allocate_node, deallocate_node},
+};
+
+/// A sorted singly linked list that several threads can edit at once.
+///
+/// Every link is guarded by the lock of the node it belongs to, and the head
+/// link by a lock of its own. Operations walk the list hand over hand,
+/// taking the next lock before releasing the previous one, so threads working
+/// in different regions of the list do not block each other. A node is only
+/// unlinked while holding the lock of its predecessor, which no other thread
+/// can then pass.
+pub struct LockingList<T> {
+    pub(super) head_lock: Mutex<()>,
+    pub(super) head: Cell<*mut LockingNode<T>>,
+    pub(super) size: AtomicUsize,
+}
+
+/// The locks only order access to the links, so a panic while holding one
+/// leaves nothing inconsistent behind.
+fn lock(mutex: &Mutex<()>) -> MutexGuard<'_, ()> {
+    mutex.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+impl<T> LockingList<T> {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            head_lock: Mutex::new(()),
+            head: Cell::new(ptr::null_mut()),
+            size: AtomicUsize::new(0),
+        }
+    }
+
+    /// Returns the number of elements. Concurrent edits may change it at any
+    /// time.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.size.load(Ordering::Relaxed)
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Walks hand over hand to the first node whose element satisfies `stop`,
+    /// returning the link that points at it (or the null link at the end)
+    /// together with the guard protecting that link.
+    // Each guard is held until the next one is taken on purpose.
+    #[allow(clippy::significant_drop_tightening)]
+    fn seek<F>(&self, mut stop: F) -> (MutexGuard<'_, ()>, &Cell<*mut LockingNode<T>>)
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let mut guard = lock(&self.head_lock);
+        let mut link = &self.head;
+
+        loop {
+            let node = link.get();
+
+            if node.is_null() {
+                return (guard, link);
+            }
+
+            // The node cannot be unlinked while its predecessor is locked.
+            let node = unsafe { &*node };
+            let node_guard = lock(&node.lock);
+
+            if stop(&node.element) {
+                return (guard, link);
+            }
+
+            guard = node_guard;
+            link = &node.next;
+        }
+    }
+
+    /// Inserts `element` in front of the first element that is not smaller.
+    pub fn insert_sorted(&self, element: T)
+    where
+        T: Ord,
+    {
+        let (guard, link) = self.seek(|current| *current >= element);
+        let node = allocate_node(LockingNode::new(element)).as_ptr();
+
+        unsafe { (*node).next.set(link.get()) };
+        link.set(node);
+        self.size.fetch_add(1, Ordering::Relaxed);
+
+        drop(guard);
+    }
+
+    /// Removes the first element equal to `element` and returns it.
+    pub fn remove(&self, element: &T) -> Option<T>
+    where
+        T: Ord,
+    {
+        let (guard, link) = self.seek(|current| current >= element);
+        let node = NonNull::new(link.get())?;
+
+        if unsafe { &node.as_ref().element } != element {
+            return None;
+        }
+
+        // Waits for any thread that passed the predecessor earlier. No other
+        // thread can reach the node afterwards.
+        let node_guard = lock(unsafe { &node.as_ref().lock });
+
+        link.set(unsafe { node.as_ref().next.get() });
+        self.size.fetch_sub(1, Ordering::Relaxed);
+
+        drop(node_guard);
+        drop(guard);
+
+        let LockingNode { element, .. } = unsafe { ptr::read(node.as_ptr()) };
+
+        unsafe { deallocate_node(node) };
+
+        Some(element)
+    }
+
+    pub fn contains(&self, element: &T) -> bool
+    where
+        T: Ord,
+    {
+        let (_guard, link) = self.seek(|current| current >= element);
+
+        NonNull::new(link.get()).is_some_and(|node| unsafe { &node.as_ref().element } == element)
+    }
+
+    /// Copies the elements into a `LinkedList`, walking hand over hand.
+    ///
+    /// The copy is sorted, but edits made concurrently behind the walk are
+    /// not reflected in it.
+    #[must_use]
+    pub fn snapshot(&self) -> LinkedList<T>
+    where
+        T: Clone,
+    {
+        let mut snapshot = LinkedList::new();
+
+        drop(self.seek(|element| {
+            snapshot.push_back(element.clone());
+
+            false
+        }));
+
+        snapshot
+    }
+}
@@ -1,7 +1,19 @@
-use std::ptr::NonNull;
+use std::{
+    cell::Cell,
+    mem::MaybeUninit,
+    ptr::{self, NonNull},
+    rc::Rc,
+    sync::{Mutex, atomic::AtomicPtr},
+};
 
 pub type Link<T> = Option<NonNull<Node<T>>>;
 
+pub type UnrolledLink<T, const N: usize> = Option<NonNull<UnrolledNode<T, N>>>;
+
+pub type XorLink<T> = Option<NonNull<XorNode<T>>>;
+
+pub type PersistentLink<T> = Option<Rc<PersistentNode<T>>>;
+
 pub struct Node<T> {
     pub previous: Link<T>,
     pub next: Link<T>,
@@ -18,9 +30,160 @@ impl<T> Node<T> {
     }
 }
 
+pub struct UnrolledNode<T, const N: usize> {
+    pub previous: UnrolledLink<T, N>,
+    pub next: UnrolledLink<T, N>,
+    pub len: usize,
+    pub elements: [MaybeUninit<T>; N],
+}
+
+impl<T, const N: usize> UnrolledNode<T, N> {
+    pub const fn new() -> Self {
+        Self {
+            previous: None,
+            next: None,
+            len: 0,
+            elements: [const { MaybeUninit::uninit() }; N],
+        }
+    }
+
+    pub const fn is_full(&self) -> bool {
+        self.len == N
+    }
+
+    pub const fn as_ptr(&self) -> *const T {
+        self.elements.as_ptr().cast::<T>()
+    }
+
+    pub const fn as_mut_ptr(&mut self) -> *mut T {
+        self.elements.as_mut_ptr().cast::<T>()
+    }
+
+    /// Inserts `element` at `offset`, shifting the following elements right.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that the node is not full and `offset <= len`.
+    pub unsafe fn insert(&mut self, offset: usize, element: T) {
+        debug_assert!(!self.is_full() && offset <= self.len);
+
+        unsafe {
+            let slot = self.as_mut_ptr().add(offset);
+
+            ptr::copy(slot, slot.add(1), self.len - offset);
+            ptr::write(slot, element);
+        }
+
+        self.len += 1;
+    }
+
+    /// Removes the element at `offset`, shifting the following elements left.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that `offset < len`.
+    pub unsafe fn remove(&mut self, offset: usize) -> T {
+        debug_assert!(offset < self.len);
+
+        self.len -= 1;
+
+        unsafe {
+            let slot = self.as_mut_ptr().add(offset);
+            let element = ptr::read(slot);
+
+            ptr::copy(slot.add(1), slot, self.len - offset);
+
+            element
+        }
+    }
+}
+
+/// A node storing `previous ^ next` addresses in a single word.
+///
+/// Addresses are exposed when stored so that `with_exposed_provenance_mut`
+/// can turn them back into usable pointers.
+pub struct XorNode<T> {
+    pub link: usize,
+    pub element: T,
+}
+
+impl<T> XorNode<T> {
+    pub const fn new(element: T) -> Self {
+        Self { link: 0, element }
+    }
+
+    /// Returns the neighbour on the opposite side of `neighbour`.
+    pub fn other(&self, neighbour: XorLink<T>) -> XorLink<T> {
+        from_address(self.link ^ address(neighbour))
+    }
+}
+
+pub fn address<T>(link: XorLink<T>) -> usize {
+    link.map_or(0, |node| node.as_ptr().expose_provenance())
+}
+
+pub const fn from_address<T>(address: usize) -> XorLink<T> {
+    NonNull::new(ptr::with_exposed_provenance_mut(address))
+}
+
+/// A node that may be shared by several versions of a `PersistentList`.
+pub struct PersistentNode<T> {
+    pub element: T,
+    pub next: PersistentLink<T>,
+}
+
+impl<T> PersistentNode<T> {
+    pub const fn new(element: T, next: PersistentLink<T>) -> Self {
+        Self { element, next }
+    }
+}
+
+/// A node of a `ConcurrentQueue`. The queue always holds one stub node whose
+/// element is uninitialized or has already been taken.
+pub struct QueueNode<T> {
+    pub next: AtomicPtr<Self>,
+    pub element: MaybeUninit<T>,
+}
+
+impl<T> QueueNode<T> {
+    pub const fn new(element: T) -> Self {
+        Self {
+            next: AtomicPtr::new(ptr::null_mut()),
+            element: MaybeUninit::new(element),
+        }
+    }
+
+    pub const fn stub() -> Self {
+        Self {
+            next: AtomicPtr::new(ptr::null_mut()),
+            element: MaybeUninit::uninit(),
+        }
+    }
+}
+
+/// A node of a `LockingList`. Its `next` link is only read or written while
+/// `lock` is held.
+pub struct LockingNode<T> {
+    pub lock: Mutex<()>,
+    pub next: Cell<*mut Self>,
+    pub element: T,
+}
+
+impl<T> LockingNode<T> {
+    pub const fn new(element: T) -> Self {
+        Self {
+            lock: Mutex::new(()),
+            next: Cell::new(ptr::null_mut()),
+            element,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::Node;
+    use std::rc::Rc;
+
+    use super::{Node, PersistentNode, UnrolledNode, XorNode};
 
     #[test]
     fn create_new_node() {
@@ -30,4 +193,49 @@ mod tests {
         assert!(node.next.is_none());
         assert_eq!(node.element, 1);
     }
+
+    #[test]
+    fn create_new_unrolled_node() {
+        let node = UnrolledNode::<i32, 4>::new();
+
+        assert!(node.previous.is_none());
+        assert!(node.next.is_none());
+        assert_eq!(node.len, 0);
+    }
+
+    #[test]
+    fn insert_and_remove_in_unrolled_node() {
+        let mut node = UnrolledNode::<i32, 4>::new();
+
+        unsafe {
+            node.insert(0, 1);
+            node.insert(1, 3);
+            node.insert(1, 2);
+
+            assert_eq!(node.len, 3);
+            assert_eq!(node.remove(0), 1);
+            assert_eq!(node.remove(1), 3);
+            assert_eq!(node.remove(0), 2);
+        }
+
+        assert_eq!(node.len, 0);
+    }
+
+    #[test]
+    fn create_new_xor_node() {
+        let node = XorNode::new(1);
+
+        assert_eq!(node.link, 0);
+        assert!(node.other(None).is_none());
+        assert_eq!(node.element, 1);
+    }
+
+    #[test]
+    fn create_new_persistent_node() {
+        let tail = Rc::new(PersistentNode::new(2, None));
+        let node = PersistentNode::new(1, Some(Rc::clone(&tail)));
+
+        assert_eq!(node.element, 1);
+        assert!(node.next.is_some_and(|next| Rc::ptr_eq(&next, &tail)));
+    }
 }
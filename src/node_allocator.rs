@@ -4,13 +4,11 @@ use std::{
     ptr::{self, NonNull},
 };
 
-use super::node::Node;
+pub fn allocate_node<N>(node: N) -> NonNull<N> {
+    assert!(mem::size_of::<N>() != 0);
 
-pub fn allocate_node<T>(node: Node<T>) -> NonNull<Node<T>> {
-    assert!(mem::size_of::<Node<T>>() != 0);
-
-    let layout = Layout::new::<Node<T>>();
-    let raw_ptr = unsafe { alloc(layout).cast::<Node<T>>() };
+    let layout = Layout::new::<N>();
+    let raw_ptr = unsafe { alloc(layout).cast::<N>() };
 
     assert!(!raw_ptr.is_null());
 
@@ -26,17 +24,18 @@ pub fn allocate_node<T>(node: Node<T>) -> NonNull<Node<T>> {
 /// # Safety
 ///
 /// The caller must ensure that:
-/// - `node` was allocated by `allocate_node` with the same `T`.
+/// - `node` was allocated by `allocate_node` with the same node type `N`.
 /// - `node` is valid and properly aligned.
 /// - `node` is not used after this call (no dangling references).
 /// - This function is called at most once for the same node.
-pub unsafe fn deallocate_node<T>(node: NonNull<Node<T>>) {
-    unsafe { dealloc(node.as_ptr().cast::<u8>(), Layout::new::<Node<T>>()) };
+pub unsafe fn deallocate_node<N>(node: NonNull<N>) {
+    unsafe { dealloc(node.as_ptr().cast::<u8>(), Layout::new::<N>()) };
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{Node, allocate_node, deallocate_node};
+    use super::{allocate_node, deallocate_node};
+    use crate::node::Node;
 
     #[test]
     fn allocate_new_node() {
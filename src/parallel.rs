@@ -0,0 +1,275 @@
+use std::{
+    any::Any, cmp::Ordering, marker::PhantomData, num::NonZero, panic, ptr::NonNull, thread,
+};
+
+use super::{
+    LinkedList,
+    node::{Link, Node},
+    sort,
+};
+
+/// Lists shorter than this per available thread are not worth splitting.
+const MIN_SEGMENT_LEN: usize = 4096;
+
+fn segment_count(len: usize) -> usize {
+    thread::available_parallelism()
+        .map_or(1, NonZero::get)
+        .min(len.div_ceil(MIN_SEGMENT_LEN))
+        .max(1)
+}
+
+/// Returns the first node of each of `count` runs of roughly equal length,
+/// together with the length of the run.
+fn segment_starts<T>(list: &LinkedList<T>, count: usize) -> Vec<(NonNull<Node<T>>, usize)> {
+    let segment_len = list.size.div_ceil(count);
+    let mut starts = Vec::with_capacity(count);
+    let mut current_node = list.head;
+    let mut remaining = list.size;
+
+    while let Some(node) = current_node {
+        let len = segment_len.min(remaining);
+
+        starts.push((node, len));
+        remaining -= len;
+
+        for _ in 0..len {
+            current_node = current_node.and_then(|node| unsafe { node.as_ref().next });
+        }
+    }
+
+    starts
+}
+
+/// Cuts the chain of `list` into `count` lists of roughly equal length,
+/// leaving `list` empty.
+fn cut<T>(list: &mut LinkedList<T>, count: usize) -> Vec<LinkedList<T>> {
+    let starts = segment_starts(list, count);
+
+    list.head = None;
+    list.tail = None;
+    list.size = 0;
+
+    starts
+        .into_iter()
+        .map(|(first, len)| unsafe {
+            let mut segment_tail = first;
+
+            for _ in 1..len {
+                segment_tail = segment_tail.as_ref().next.unwrap_unchecked();
+            }
+
+            if let Some(mut next) = segment_tail.as_mut().next.take() {
+                next.as_mut().previous = None;
+            }
+
+            LinkedList {
+                head: Some(first),
+                tail: Some(segment_tail),
+                size: len,
+            }
+        })
+        .collect()
+}
+
+/// Links the nodes of `other` after the tail of `list`, leaving `other` empty.
+const fn append<T>(list: &mut LinkedList<T>, other: &mut LinkedList<T>) {
+    let Some(mut other_head) = other.head.take() else {
+        return;
+    };
+
+    match list.tail {
+        Some(mut tail) => unsafe {
+            tail.as_mut().next = Some(other_head);
+            other_head.as_mut().previous = Some(tail);
+        },
+        None => list.head = Some(other_head),
+    }
+
+    list.tail = other.tail.take();
+    list.size += other.size;
+    other.size = 0;
+}
+
+pub fn for_each_segment<T, F>(list: &mut LinkedList<T>, operation: F)
+where
+    T: Send,
+    F: Fn(&mut LinkedList<T>) + Sync,
+{
+    for_each_in_segments(list, segment_count(list.size), operation);
+}
+
+/// Runs `operation` on `count` segments of `list` in parallel, then relinks
+/// the segments in their original order. A panicking worker is re-raised
+/// after the list has been stitched back together.
+pub fn for_each_in_segments<T, F>(list: &mut LinkedList<T>, count: usize, operation: F)
+where
+    T: Send,
+    F: Fn(&mut LinkedList<T>) + Sync,
+{
+    if count <= 1 || list.size <= 1 {
+        operation(list);
+
+        return;
+    }
+
+    let mut segments = cut(list, count);
+    let panic_payload = run_workers(segments.iter_mut(), operation);
+
+    for segment in &mut segments {
+        append(list, segment);
+    }
+
+    if let Some(payload) = panic_payload {
+        panic::resume_unwind(payload);
+    }
+}
+
+/// Runs `operation` on each item on its own scoped thread and waits for all
+/// of them, returning the payload of the first worker that panicked.
+fn run_workers<I, F>(items: I, operation: F) -> Option<Box<dyn Any + Send>>
+where
+    I: IntoIterator<Item: Send>,
+    F: Fn(I::Item) + Sync,
+{
+    let mut panic_payload = None;
+
+    thread::scope(|scope| {
+        let operation = &operation;
+        let workers: Vec<_> = items
+            .into_iter()
+            .map(|item| scope.spawn(move || operation(item)))
+            .collect();
+
+        for worker in workers {
+            if let Err(payload) = worker.join() {
+                panic_payload.get_or_insert(payload);
+            }
+        }
+    });
+
+    panic_payload
+}
+
+pub fn sort_by<T, F>(list: &mut LinkedList<T>, compare: F)
+where
+    T: Send,
+    F: Fn(&T, &T) -> Ordering + Sync,
+{
+    sort_in_segments(list, segment_count(list.size), compare);
+}
+
+/// Sorts `count` segments of `list` in parallel, then merges neighbouring
+/// runs pairwise in parallel rounds until one run is left. Merging only ever
+/// joins adjacent runs, so the sort stays stable. A panicking comparator is
+/// re-raised once the runs have been linked back in order.
+pub fn sort_in_segments<T, F>(list: &mut LinkedList<T>, count: usize, compare: F)
+where
+    T: Send,
+    F: Fn(&T, &T) -> Ordering + Sync,
+{
+    if count <= 1 || list.size <= 1 {
+        sort::sort_by(list, compare);
+
+        return;
+    }
+
+    let mut runs = cut(list, count);
+    let mut panic_payload = run_workers(runs.iter_mut(), |run| sort::sort_by(run, &compare));
+
+    while panic_payload.is_none() && runs.len() > 1 {
+        panic_payload = run_workers(runs.chunks_mut(2), |pair| {
+            if let [left, right] = pair {
+                sort::merge_by(left, right, &compare);
+            }
+        });
+
+        runs.retain(|run| !run.is_empty());
+    }
+
+    for run in &mut runs {
+        append(list, run);
+    }
+
+    if let Some(payload) = panic_payload {
+        panic::resume_unwind(payload);
+    }
+}
+
+/// A run of nodes read by a single worker.
+struct Segment<'a, T> {
+    first: Link<T>,
+    len: usize,
+    _marker: PhantomData<&'a T>,
+}
+
+// A segment only hands out shared references to its elements.
+unsafe impl<T: Sync> Send for Segment<'_, T> {}
+
+impl<'a, T> Segment<'a, T> {
+    fn map<U, F>(self, f: &F) -> Vec<U>
+    where
+        F: Fn(&'a T) -> U,
+    {
+        let mut mapped = Vec::with_capacity(self.len);
+        let mut current_node = self.first;
+
+        for _ in 0..self.len {
+            let Some(node) = current_node else {
+                break;
+            };
+            let node_ref: &'a Node<T> = unsafe { node.as_ref() };
+
+            mapped.push(f(&node_ref.element));
+            current_node = node_ref.next;
+        }
+
+        mapped
+    }
+}
+
+pub fn map_into_vec<T, U, F>(list: &LinkedList<T>, f: F) -> Vec<U>
+where
+    T: Sync,
+    U: Send,
+    F: Fn(&T) -> U + Sync,
+{
+    map_in_segments(list, segment_count(list.size), f)
+}
+
+/// Maps `count` segments of `list` in parallel, concatenating the results in
+/// list order.
+pub fn map_in_segments<T, U, F>(list: &LinkedList<T>, count: usize, f: F) -> Vec<U>
+where
+    T: Sync,
+    U: Send,
+    F: Fn(&T) -> U + Sync,
+{
+    if count <= 1 || list.size <= 1 {
+        return list.iter().map(f).collect();
+    }
+
+    let segments = segment_starts(list, count)
+        .into_iter()
+        .map(|(first, len)| Segment {
+            first: Some(first),
+            len,
+            _marker: PhantomData,
+        });
+
+    thread::scope(|scope| {
+        let f = &f;
+        let workers: Vec<_> = segments
+            .map(|segment| scope.spawn(move || segment.map(f)))
+            .collect();
+        let mut mapped = Vec::with_capacity(list.size);
+
+        for worker in workers {
+            match worker.join() {
+                Ok(segment) => mapped.extend(segment),
+                Err(payload) => panic::resume_unwind(payload),
+            }
+        }
+
+        mapped
+    })
+}
@@ -0,0 +1,275 @@
+use std::{
+    error::Error,
+    fmt::{self, Display, Write},
+    str::FromStr,
+};
+
+use super::LinkedList;
+
+const SEPARATOR: &str = "<->";
+
+/// An error returned when parsing a `LinkedList` from its `Display` format.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseListError<E> {
+    /// Byte offset in the input where the problem was found.
+    pub position: usize,
+    pub kind: ParseListErrorKind<E>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseListErrorKind<E> {
+    /// The input does not start with `[`.
+    MissingOpeningBracket,
+    /// The input ends before the closing `]`.
+    MissingClosingBracket,
+    /// An unescaped `[` appears inside the list.
+    UnexpectedBracket,
+    /// A separator is not surrounded by elements.
+    EmptyElement,
+    /// The input ends right after a `\`.
+    DanglingEscape,
+    /// Non-whitespace characters follow the closing `]`.
+    TrailingCharacters,
+    /// The element starting at `position` failed to parse.
+    Element(E),
+}
+
+impl<E: Display> Display for ParseListError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.kind {
+            ParseListErrorKind::MissingOpeningBracket => write!(f, "expected `[`")?,
+            ParseListErrorKind::MissingClosingBracket => write!(f, "expected `]`")?,
+            ParseListErrorKind::UnexpectedBracket => write!(f, "unexpected unescaped `[`")?,
+            ParseListErrorKind::EmptyElement => write!(f, "empty element")?,
+            ParseListErrorKind::DanglingEscape => write!(f, "dangling `\\`")?,
+            ParseListErrorKind::TrailingCharacters => {
+                write!(f, "unexpected characters after `]`")?;
+            }
+            ParseListErrorKind::Element(error) => write!(f, "invalid element: {error}")?,
+        }
+
+        write!(f, " at position {}", self.position)
+    }
+}
+
+impl<E: Error + 'static> Error for ParseListError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match &self.kind {
+            ParseListErrorKind::Element(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+/// Escapes an element that renders as nothing at all, which would otherwise
+/// leave nothing between its brackets or separators.
+const EMPTY_ELEMENT: char = 'e';
+
+/// A `fmt::Write` adapter escaping an element as it is rendered. Characters
+/// whose escaping depends on what follows them, a possible separator or
+/// whitespace that may end the element, are held back until that is known.
+struct EscapingWriter<'a, 'b> {
+    f: &'a mut fmt::Formatter<'b>,
+    pending: String,
+    started: bool,
+}
+
+impl EscapingWriter<'_, '_> {
+    fn flush(&mut self, finished: bool) -> fmt::Result {
+        let mut written = 0;
+
+        for (index, c) in self.pending.char_indices() {
+            let rest = &self.pending[index..];
+            let escaped = match c {
+                '\\' | '[' | ']' => true,
+                '<' if rest.starts_with(SEPARATOR) => true,
+                '<' if !finished && SEPARATOR.starts_with(rest) => break,
+                c if c.is_whitespace() && rest.trim_start().is_empty() => {
+                    if !finished {
+                        break;
+                    }
+
+                    rest.len() == c.len_utf8()
+                }
+                _ => false,
+            };
+
+            if escaped {
+                self.f.write_char('\\')?;
+            }
+
+            self.f.write_char(c)?;
+            written = index + c.len_utf8();
+        }
+
+        self.pending.drain(..written);
+
+        Ok(())
+    }
+
+    fn finish(mut self) -> fmt::Result {
+        self.flush(true)?;
+
+        if !self.started {
+            write!(self.f, "\\{EMPTY_ELEMENT}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::Write for EscapingWriter<'_, '_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for c in s.chars() {
+            if !self.started {
+                self.started = true;
+
+                if c.is_whitespace() {
+                    write!(self.f, "\\{c}")?;
+                    continue;
+                }
+            }
+
+            self.pending.push(c);
+        }
+
+        self.flush(false)
+    }
+}
+
+/// Writes an element, escaping the characters that would otherwise be read
+/// back as list syntax: `\`, brackets, separators and the whitespace at either
+/// end of the element. An element rendering as the empty string is written
+/// as `\e`.
+pub fn write_escaped<T: Display>(f: &mut fmt::Formatter<'_>, element: &T) -> fmt::Result {
+    let mut writer = EscapingWriter {
+        f,
+        pending: String::new(),
+        started: false,
+    };
+
+    write!(writer, "{element}")?;
+    writer.finish()
+}
+
+/// Collects the characters of a single element, dropping unescaped whitespace
+/// at either end.
+struct ElementBuffer {
+    text: String,
+    significant_len: usize,
+    start: Option<usize>,
+}
+
+impl ElementBuffer {
+    const fn new() -> Self {
+        Self {
+            text: String::new(),
+            significant_len: 0,
+            start: None,
+        }
+    }
+
+    /// Starts the element at `position` without adding to its text.
+    fn mark(&mut self, position: usize) {
+        self.start.get_or_insert(position);
+    }
+
+    fn push(&mut self, c: char, position: usize, significant: bool) {
+        if !significant && self.start.is_none() {
+            return;
+        }
+
+        self.start.get_or_insert(position);
+        self.text.push(c);
+
+        if significant {
+            self.significant_len = self.text.len();
+        }
+    }
+
+    fn finish<T: FromStr>(
+        &mut self,
+        list: &mut LinkedList<T>,
+        position: usize,
+    ) -> Result<(), ParseListError<T::Err>> {
+        let Some(start) = self.start.take() else {
+            return Err(ParseListError {
+                position,
+                kind: ParseListErrorKind::EmptyElement,
+            });
+        };
+
+        self.text.truncate(self.significant_len);
+
+        let element = self.text.parse().map_err(|error| ParseListError {
+            position: start,
+            kind: ParseListErrorKind::Element(error),
+        })?;
+
+        list.push_back(element);
+
+        self.text.clear();
+        self.significant_len = 0;
+
+        Ok(())
+    }
+}
+
+pub fn parse_list<T: FromStr>(input: &str) -> Result<LinkedList<T>, ParseListError<T::Err>> {
+    let error = |position, kind| Err(ParseListError { position, kind });
+
+    let open = input.len() - input.trim_start().len();
+
+    if !input[open..].starts_with('[') {
+        return error(open, ParseListErrorKind::MissingOpeningBracket);
+    }
+
+    let mut list = LinkedList::new();
+    let mut element = ElementBuffer::new();
+    let mut separated = false;
+    let mut chars = input.char_indices().skip_while(|(index, _)| *index <= open);
+
+    while let Some((position, c)) = chars.next() {
+        match c {
+            '\\' => {
+                let Some((_, escaped)) = chars.next() else {
+                    return error(position, ParseListErrorKind::DanglingEscape);
+                };
+
+                if escaped == EMPTY_ELEMENT {
+                    element.mark(position);
+                } else {
+                    element.push(escaped, position, true);
+                }
+            }
+            '<' if input[position..].starts_with(SEPARATOR) => {
+                chars.nth(SEPARATOR.len() - 2);
+
+                element.finish(&mut list, position)?;
+                separated = true;
+            }
+            '[' => return error(position, ParseListErrorKind::UnexpectedBracket),
+            ']' => {
+                if separated || element.start.is_some() {
+                    element.finish(&mut list, position)?;
+                }
+
+                let trailing = &input[position + 1..];
+
+                if !trailing.trim_end().is_empty() {
+                    let offset = trailing.len() - trailing.trim_start().len();
+
+                    return error(
+                        position + 1 + offset,
+                        ParseListErrorKind::TrailingCharacters,
+                    );
+                }
+
+                return Ok(list);
+            }
+            c => element.push(c, position, !c.is_whitespace()),
+        }
+    }
+
+    error(input.len(), ParseListErrorKind::MissingClosingBracket)
+}
@@ -0,0 +1,85 @@
+use std::rc::Rc;
+
+use super::node::{PersistentLink, PersistentNode};
+
+pub struct PersistentIter<'a, T> {
+    pub(super) current: Option<&'a PersistentNode<T>>,
+    pub(super) remaining: usize,
+}
+
+/// An immutable singly linked list whose versions share their tails.
+///
+/// `cons` and `tail` build new versions in constant time without touching
+/// the nodes of the original, and `clone` only bumps a reference count.
+pub struct PersistentList<T> {
+    pub(super) head: PersistentLink<T>,
+    pub(super) size: usize,
+}
+
+impl<T> PersistentList<T> {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            head: None,
+            size: 0,
+        }
+    }
+
+    /// Returns a new version with `element` in front of this one.
+    #[must_use]
+    pub fn cons(&self, element: T) -> Self {
+        Self {
+            head: Some(Rc::new(PersistentNode::new(element, self.head.clone()))),
+            size: self.size + 1,
+        }
+    }
+
+    /// Same as `cons`.
+    #[must_use]
+    pub fn push_front(&self, element: T) -> Self {
+        self.cons(element)
+    }
+
+    #[must_use]
+    pub fn head(&self) -> Option<&T> {
+        self.head.as_ref().map(|node| &node.element)
+    }
+
+    /// Returns the version without the first element, or `None` if the list
+    /// is empty.
+    #[must_use]
+    pub fn tail(&self) -> Option<Self> {
+        self.head.as_ref().map(|node| Self {
+            head: node.next.clone(),
+            size: self.size - 1,
+        })
+    }
+
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.size
+    }
+
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.size == 0
+    }
+
+    /// Returns whether both lists start with the same shared node.
+    #[must_use]
+    pub fn ptr_eq(&self, other: &Self) -> bool {
+        match (&self.head, &other.head) {
+            (Some(node), Some(other_node)) => Rc::ptr_eq(node, other_node),
+            (None, None) => true,
+            _ => false,
+        }
+    }
+
+    #[must_use]
+    pub fn iter(&self) -> PersistentIter<'_, T> {
+        PersistentIter {
+            current: self.head.as_deref(),
+            remaining: self.size,
+        }
+    }
+}
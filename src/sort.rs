@@ -0,0 +1,182 @@
+use std::{cmp::Ordering, ptr::NonNull};
+
+use super::{
+    LinkedList,
+    node::{Link, Node},
+};
+
+/// The null-terminated chains, threaded through `next`, that a merge sort
+/// moves nodes between.
+///
+/// Every node of the list sits on exactly one chain and the comparator only
+/// runs between moves, so dropping the chains relinks a complete list even if
+/// the comparator panicked.
+pub struct Chains<'a, T> {
+    list: &'a mut LinkedList<T>,
+    merged: Link<T>,
+    merged_tail: Link<T>,
+    left: Link<T>,
+    right: Link<T>,
+    rest: Link<T>,
+}
+
+impl<'a, T> Chains<'a, T> {
+    /// Takes the nodes of `list` as the `rest` chain. The size of the list is
+    /// left as it is.
+    const fn new(list: &'a mut LinkedList<T>) -> Self {
+        let rest = list.head.take();
+
+        list.tail = None;
+
+        Self {
+            list,
+            merged: None,
+            merged_tail: None,
+            left: None,
+            right: None,
+            rest,
+        }
+    }
+
+    /// Detaches the first `len` nodes of `rest`.
+    fn take_run(&mut self, len: usize) -> Link<T> {
+        let head = self.rest?;
+        let mut run_tail = head;
+
+        for _ in 1..len {
+            match unsafe { run_tail.as_ref().next } {
+                Some(next) => run_tail = next,
+                None => break,
+            }
+        }
+
+        self.rest = unsafe { run_tail.as_mut().next.take() };
+
+        Some(head)
+    }
+
+    /// Links the chain starting at `head` after the tail of `merged`.
+    const fn append_merged(&mut self, head: Link<T>) {
+        let Some(head) = head else {
+            return;
+        };
+
+        match self.merged_tail {
+            Some(mut tail) => unsafe { tail.as_mut().next = Some(head) },
+            None => self.merged = Some(head),
+        }
+
+        let mut tail = head;
+
+        while let Some(next) = unsafe { tail.as_ref().next } {
+            tail = next;
+        }
+
+        self.merged_tail = Some(tail);
+    }
+
+    const fn push_merged(&mut self, node: NonNull<Node<T>>) {
+        match self.merged_tail {
+            Some(mut tail) => unsafe { tail.as_mut().next = Some(node) },
+            None => self.merged = Some(node),
+        }
+
+        self.merged_tail = Some(node);
+    }
+
+    /// Merges `left` and `right` onto `merged`. The head of `left` is taken
+    /// unless the head of `right` compares less, which keeps the sort stable.
+    fn merge<F>(&mut self, compare: &mut F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        while let (Some(left), Some(right)) = (self.left, self.right) {
+            let from_right = compare(unsafe { &right.as_ref().element }, unsafe {
+                &left.as_ref().element
+            }) == Ordering::Less;
+            let (chain, mut node) = if from_right {
+                (&mut self.right, right)
+            } else {
+                (&mut self.left, left)
+            };
+
+            *chain = unsafe { node.as_mut().next.take() };
+            self.push_merged(node);
+        }
+
+        let left = self.left.take();
+        let right = self.right.take();
+
+        self.append_merged(left);
+        self.append_merged(right);
+    }
+
+    /// Joins every chain onto `merged` and restores the back links, head and
+    /// tail of the list.
+    pub(super) const fn relink(&mut self) {
+        let left = self.left.take();
+        let right = self.right.take();
+        let rest = self.rest.take();
+
+        self.append_merged(left);
+        self.append_merged(right);
+        self.append_merged(rest);
+
+        let mut previous = None;
+        let mut current_node = self.merged;
+
+        while let Some(mut node) = current_node {
+            unsafe {
+                node.as_mut().previous = previous;
+                current_node = node.as_ref().next;
+            }
+
+            previous = Some(node);
+        }
+
+        self.list.head = self.merged.take();
+        self.list.tail = previous;
+        self.merged_tail = None;
+    }
+}
+
+/// Sorts `list` stably with a bottom-up merge sort that relinks its nodes in
+/// place, without allocating.
+pub fn sort_by<T, F>(list: &mut LinkedList<T>, mut compare: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let len = list.size;
+    let mut chains = Chains::new(list);
+    let mut width = 1;
+
+    while width < len {
+        while chains.rest.is_some() {
+            chains.left = chains.take_run(width);
+            chains.right = chains.take_run(width);
+            chains.merge(&mut compare);
+        }
+
+        chains.rest = chains.merged.take();
+        chains.merged_tail = None;
+        width = width.saturating_mul(2);
+    }
+}
+
+/// Merges the sorted `other` into the sorted `list`, leaving `other` empty.
+/// Elements of `list` come first among equal ones.
+pub fn merge_by<T, F>(list: &mut LinkedList<T>, other: &mut LinkedList<T>, mut compare: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    list.size += other.size;
+    other.size = 0;
+    other.tail = None;
+
+    let right = other.head.take();
+    let mut chains = Chains::new(list);
+
+    chains.left = chains.rest.take();
+    chains.right = right;
+    chains.merge(&mut compare);
+}
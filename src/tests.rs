@@ -1,14 +1,295 @@
 use std::fmt;
 
-use super::{LinkedList, node::Node};
+use super::{
+    ConcurrentQueue, CowList, Decode, DecodeError, DiagramOptions, DiagramTrace, DisplayOptions,
+    DotOptions, Encode, FileList, FileListError, FileStorage, InvariantViolation, Journal,
+    JournalEntry, LinkedList, LockingList, ParseJournalError, ParseJournalErrorKind,
+    ParseListError, ParseListErrorKind, PersistentList, RecordedList, RecvError, RecvTimeoutError,
+    ReplayError, RunCodec, SendError, TryRecvError, TrySendError, UnrolledLinkedList,
+    XorLinkedList, channel, node::Node, parallel, replay,
+};
 
 mod utils {
+    use std::{
+        alloc::{GlobalAlloc, Layout, System},
+        cell::{Cell, RefCell},
+        cmp::Ordering,
+        fs, panic,
+        path::PathBuf,
+        process,
+        rc::Rc,
+        sync::Once,
+        thread,
+    };
+
     use super::{LinkedList, Node, fmt};
 
+    thread_local! {
+        static LIVE_ALLOCATIONS: Cell<isize> = const { Cell::new(0) };
+    }
+
+    /// Counts live heap allocations per thread so that tests running in
+    /// parallel do not observe each other's allocations.
+    pub struct CountingAllocator;
+
+    impl CountingAllocator {
+        fn record(delta: isize) {
+            let _ = LIVE_ALLOCATIONS.try_with(|live| live.set(live.get() + delta));
+        }
+    }
+
+    unsafe impl GlobalAlloc for CountingAllocator {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            let ptr = unsafe { System.alloc(layout) };
+
+            if !ptr.is_null() {
+                Self::record(1);
+            }
+
+            ptr
+        }
+
+        unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+            let ptr = unsafe { System.alloc_zeroed(layout) };
+
+            if !ptr.is_null() {
+                Self::record(1);
+            }
+
+            ptr
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            unsafe { System.dealloc(ptr, layout) };
+
+            Self::record(-1);
+        }
+
+        unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+            unsafe { System.realloc(ptr, layout, new_size) }
+        }
+    }
+
+    #[global_allocator]
+    static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+    pub fn live_allocations() -> isize {
+        LIVE_ALLOCATIONS.with(Cell::get)
+    }
+
+    /// Runs `body` and asserts that every allocation it made was freed.
+    pub fn assert_no_leaks<F: FnOnce()>(body: F) {
+        // The hook is boxed once per process, which is not a leak of `body`.
+        install_panic_hook();
+
+        let before = live_allocations();
+
+        body();
+
+        assert_eq!(live_allocations(), before, "allocations leaked");
+    }
+
+    /// A splitmix64 generator, so randomized tests need no external crates.
+    pub struct Rng(pub u64);
+
+    impl Rng {
+        pub fn next_u64(&mut self) -> u64 {
+            self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+
+            let mut z = self.0;
+
+            z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+            z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+
+            z ^ (z >> 31)
+        }
+
+        pub fn below(&mut self, bound: u64) -> u64 {
+            self.next_u64() % bound
+        }
+
+        pub fn element(&mut self) -> i32 {
+            i32::try_from(self.below(100)).expect("bound fits in i32")
+        }
+    }
+
+    /// Hands out `DropCounter` elements and remembers how often each of them
+    /// was dropped.
+    pub struct DropTracker {
+        drops: Rc<RefCell<Vec<usize>>>,
+    }
+
+    impl DropTracker {
+        pub fn new() -> Self {
+            // Allocated up front so that tracking elements inside
+            // `assert_no_leaks` does not count as a leak.
+            Self {
+                drops: Rc::new(RefCell::new(Vec::with_capacity(16))),
+            }
+        }
+
+        pub fn track(&self, value: i32) -> DropCounter {
+            DropCounter::register(&self.drops, value, false)
+        }
+
+        pub fn track_panicking(&self, value: i32) -> DropCounter {
+            DropCounter::register(&self.drops, value, true)
+        }
+
+        pub fn created(&self) -> usize {
+            self.drops.borrow().len()
+        }
+
+        pub fn dropped(&self) -> usize {
+            self.drops
+                .borrow()
+                .iter()
+                .filter(|drops| **drops > 0)
+                .count()
+        }
+
+        pub fn assert_all_dropped_once(&self) {
+            for (id, drops) in self.drops.borrow().iter().enumerate() {
+                assert_eq!(*drops, 1, "element {id} was dropped {drops} times");
+            }
+        }
+    }
+
+    /// An element that records its drops and optionally panics in `Drop`.
+    pub struct DropCounter {
+        id: usize,
+        pub value: i32,
+        panics: bool,
+        drops: Rc<RefCell<Vec<usize>>>,
+    }
+
+    impl DropCounter {
+        fn register(drops: &Rc<RefCell<Vec<usize>>>, value: i32, panics: bool) -> Self {
+            let mut counts = drops.borrow_mut();
+
+            counts.push(0);
+
+            Self {
+                id: counts.len() - 1,
+                value,
+                panics,
+                drops: Rc::clone(drops),
+            }
+        }
+    }
+
+    impl Clone for DropCounter {
+        fn clone(&self) -> Self {
+            Self::register(&self.drops, self.value, self.panics)
+        }
+    }
+
+    impl PartialEq for DropCounter {
+        fn eq(&self, other: &Self) -> bool {
+            self.value == other.value
+        }
+    }
+
+    impl Eq for DropCounter {}
+
+    impl PartialOrd for DropCounter {
+        fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl Ord for DropCounter {
+        fn cmp(&self, other: &Self) -> Ordering {
+            self.value.cmp(&other.value)
+        }
+    }
+
+    impl Drop for DropCounter {
+        fn drop(&mut self) {
+            self.drops.borrow_mut()[self.id] += 1;
+
+            assert!(
+                !self.panics || thread::panicking(),
+                "element {} panicked while dropping",
+                self.id
+            );
+        }
+    }
+
+    fn install_panic_hook() {
+        static INSTALL_HOOK: Once = Once::new();
+
+        INSTALL_HOOK.call_once(|| {
+            let default_hook = panic::take_hook();
+
+            panic::set_hook(Box::new(move |info| {
+                if !EXPECTING_PANIC.with(Cell::get) {
+                    default_hook(info);
+                }
+            }));
+        });
+    }
+
+    thread_local! {
+        static EXPECTING_PANIC: Cell<bool> = const { Cell::new(false) };
+    }
+
+    /// Runs `body` and asserts that it panicked.
+    ///
+    /// The panic is not reported, since the default hook captures a backtrace
+    /// whose allocations would outlive `assert_no_leaks`.
+    pub fn expect_panic<F: FnOnce()>(body: F) {
+        install_panic_hook();
+
+        EXPECTING_PANIC.with(|expecting| expecting.set(true));
+
+        let result = panic::catch_unwind(panic::AssertUnwindSafe(body));
+
+        EXPECTING_PANIC.with(|expecting| expecting.set(false));
+
+        assert!(result.is_err(), "expected a panic");
+    }
+
+    /// A scratch directory that is removed with its contents once dropped.
+    pub struct TempDir(pub PathBuf);
+
+    impl TempDir {
+        pub fn new(name: &str) -> Self {
+            let path =
+                std::env::temp_dir().join(format!("linked-list-test-{}-{name}", process::id()));
+
+            fs::create_dir_all(&path).expect("temp dir should be creatable");
+
+            Self(path)
+        }
+
+        pub fn file_count(&self) -> usize {
+            fs::read_dir(&self.0)
+                .expect("temp dir should exist")
+                .count()
+        }
+    }
+
+    impl Drop for TempDir {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.0);
+        }
+    }
+
     pub fn new_list<T>() -> LinkedList<T> {
         LinkedList::<T>::new()
     }
 
+    pub fn list_of<T, I: IntoIterator<Item = T>>(elements: I) -> LinkedList<T> {
+        let mut list = new_list();
+
+        for element in elements {
+            list.push_back(element);
+        }
+
+        list
+    }
+
     pub fn raw_head<T>(list: &LinkedList<T>) -> &Node<T> {
         unsafe { list.head.expect("head should be Some").as_ref() }
     }
@@ -635,3 +916,3700 @@ mod iter {
         assert_eq!(iter.next(), Some(&2));
     }
 }
+
+mod unrolled {
+    use super::UnrolledLinkedList;
+
+    fn collect<const N: usize>(list: &UnrolledLinkedList<i32, N>) -> Vec<i32> {
+        list.iter().copied().collect()
+    }
+
+    fn node_lengths<const N: usize>(list: &UnrolledLinkedList<i32, N>) -> Vec<usize> {
+        let mut lengths = vec![];
+        let mut current_node = list.head;
+
+        while let Some(node) = current_node {
+            let node_ref = unsafe { node.as_ref() };
+
+            lengths.push(node_ref.len);
+            current_node = node_ref.next;
+        }
+
+        lengths
+    }
+
+    #[test]
+    fn create_unrolled_list() {
+        let list = UnrolledLinkedList::<i32, 4>::new();
+
+        assert!(list.head.is_none());
+        assert!(list.tail.is_none());
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    fn push_back_fills_nodes_before_allocating() {
+        let mut list = UnrolledLinkedList::<i32, 4>::new();
+
+        for x in 1..=6 {
+            list.push_back(x);
+        }
+
+        assert_eq!(collect(&list), vec![1, 2, 3, 4, 5, 6]);
+        assert_eq!(node_lengths(&list), vec![4, 2]);
+        assert_eq!(list.len(), 6);
+    }
+
+    #[test]
+    fn push_front_fills_nodes_before_allocating() {
+        let mut list = UnrolledLinkedList::<i32, 4>::new();
+
+        for x in 1..=6 {
+            list.push_front(x);
+        }
+
+        assert_eq!(collect(&list), vec![6, 5, 4, 3, 2, 1]);
+        assert_eq!(node_lengths(&list), vec![2, 4]);
+    }
+
+    #[test]
+    fn pop_from_both_ends() {
+        let mut list = UnrolledLinkedList::<i32, 2>::new();
+
+        for x in 1..=5 {
+            list.push_back(x);
+        }
+
+        assert_eq!(list.pop_front(), Some(1));
+        assert_eq!(list.pop_back(), Some(5));
+        assert_eq!(list.pop_front(), Some(2));
+        assert_eq!(list.pop_back(), Some(4));
+        assert_eq!(list.pop_back(), Some(3));
+        assert_eq!(list.pop_back(), None);
+        assert_eq!(list.pop_front(), None);
+
+        assert!(list.head.is_none());
+        assert!(list.tail.is_none());
+    }
+
+    #[test]
+    fn front_and_back_access() {
+        let mut list = UnrolledLinkedList::<i32, 3>::new();
+
+        assert!(list.front().is_none());
+        assert!(list.back().is_none());
+
+        for x in 1..=4 {
+            list.push_back(x);
+        }
+
+        if let Some(element) = list.front_mut() {
+            *element = 10;
+        }
+
+        if let Some(element) = list.back_mut() {
+            *element = 40;
+        }
+
+        assert_eq!(list.front(), Some(&10));
+        assert_eq!(list.back(), Some(&40));
+    }
+
+    #[test]
+    fn get_walks_across_nodes() {
+        let mut list = UnrolledLinkedList::<i32, 3>::new();
+
+        for x in 0..10 {
+            list.push_back(x);
+        }
+
+        for (index, x) in (0..10).enumerate() {
+            assert_eq!(list.get(index), Some(&x));
+        }
+
+        assert!(list.get(10).is_none());
+
+        if let Some(element) = list.get_mut(7) {
+            *element = 70;
+        }
+
+        assert_eq!(list.get(7), Some(&70));
+    }
+
+    #[test]
+    fn insert_splits_full_node() {
+        let mut list = UnrolledLinkedList::<i32, 4>::new();
+
+        for x in [1, 2, 4, 5] {
+            list.push_back(x);
+        }
+
+        list.insert(2, 3);
+
+        assert_eq!(collect(&list), vec![1, 2, 3, 4, 5]);
+        assert_eq!(node_lengths(&list), vec![3, 2]);
+
+        list.insert(0, 0);
+        list.insert(6, 6);
+
+        assert_eq!(collect(&list), vec![0, 1, 2, 3, 4, 5, 6]);
+        assert_eq!(list.len(), 7);
+    }
+
+    #[test]
+    fn insert_into_empty_list() {
+        let mut list = UnrolledLinkedList::<i32, 1>::new();
+
+        list.insert(0, 2);
+        list.insert(0, 1);
+        list.insert(2, 3);
+
+        assert_eq!(collect(&list), vec![1, 2, 3]);
+        assert_eq!(node_lengths(&list), vec![1, 1, 1]);
+    }
+
+    #[test]
+    #[should_panic(expected = "insertion index")]
+    fn insert_out_of_bounds_panics() {
+        let mut list = UnrolledLinkedList::<i32, 4>::new();
+
+        list.insert(1, 1);
+    }
+
+    #[test]
+    fn remove_merges_nodes() {
+        let mut list = UnrolledLinkedList::<i32, 4>::new();
+
+        for x in 1..=8 {
+            list.push_back(x);
+        }
+
+        assert_eq!(node_lengths(&list), vec![4, 4]);
+
+        assert_eq!(list.remove(1), Some(2));
+        assert_eq!(list.remove(1), Some(3));
+        assert_eq!(node_lengths(&list), vec![2, 4]);
+
+        assert_eq!(list.remove(2), Some(5));
+        assert_eq!(list.remove(2), Some(6));
+
+        assert_eq!(collect(&list), vec![1, 4, 7, 8]);
+        assert_eq!(node_lengths(&list), vec![4]);
+        assert_eq!(list.remove(4), None);
+    }
+
+    #[test]
+    fn remove_last_element_of_node_frees_it() {
+        let mut list = UnrolledLinkedList::<i32, 1>::new();
+
+        for x in 1..=3 {
+            list.push_back(x);
+        }
+
+        assert_eq!(list.remove(1), Some(2));
+        assert_eq!(collect(&list), vec![1, 3]);
+        assert_eq!(node_lengths(&list), vec![1, 1]);
+    }
+
+    #[test]
+    fn contains_and_clear() {
+        let mut list = UnrolledLinkedList::<i32, 4>::new();
+
+        for x in 1..=5 {
+            list.push_back(x);
+        }
+
+        assert!(list.contains(&5));
+        assert!(!list.contains(&6));
+
+        list.clear();
+
+        assert!(list.is_empty());
+        assert!(list.head.is_none());
+    }
+
+    #[test]
+    fn iter_mut_modifies_elements() {
+        let mut list = UnrolledLinkedList::<i32, 2>::new();
+
+        for x in 1..=5 {
+            list.push_back(x);
+        }
+
+        for x in &mut list {
+            *x *= 2;
+        }
+
+        assert_eq!(collect(&list), vec![2, 4, 6, 8, 10]);
+    }
+
+    #[test]
+    fn into_iter_consumes_list() {
+        let mut list = UnrolledLinkedList::<String, 2>::new();
+
+        for x in ["a", "b", "c"] {
+            list.push_back(x.to_string());
+        }
+
+        let mut iter = list.into_iter();
+
+        assert_eq!(iter.next().as_deref(), Some("a"));
+
+        let rest: Vec<String> = iter.collect();
+
+        assert_eq!(rest, vec!["b", "c"]);
+    }
+
+    #[test]
+    fn for_loop_on_ref_list() {
+        let mut list = UnrolledLinkedList::<i32, 3>::new();
+
+        for x in 1..=4 {
+            list.push_back(x);
+        }
+
+        let mut sum = 0;
+
+        for x in &list {
+            sum += *x;
+        }
+
+        assert_eq!(sum, 10);
+    }
+}
+
+mod xor {
+    use super::XorLinkedList;
+
+    fn new_list<T>() -> XorLinkedList<T> {
+        XorLinkedList::<T>::new()
+    }
+
+    fn assert_empty_list<T>(list: &XorLinkedList<T>) {
+        assert!(list.head.is_none());
+        assert!(list.tail.is_none());
+        assert_eq!(list.size, 0);
+    }
+
+    fn assert_links(list: &XorLinkedList<i32>, expected: &[i32]) {
+        let forward: Vec<i32> = list.iter().copied().collect();
+        let mut backward: Vec<i32> = list.iter().rev().copied().collect();
+
+        backward.reverse();
+
+        assert_eq!(forward, expected);
+        assert_eq!(backward, expected);
+        assert_eq!(list.size, expected.len());
+
+        if let (Some(head), Some(tail)) = (list.head, list.tail) {
+            unsafe {
+                assert!(head.as_ref().other(None).is_some() == (expected.len() > 1));
+                assert!(tail.as_ref().other(None).is_some() == (expected.len() > 1));
+            }
+        }
+    }
+
+    #[test]
+    fn create_list() {
+        let list = new_list::<i32>();
+
+        assert_empty_list(&list);
+    }
+
+    #[test]
+    fn create_list_using_default() {
+        let list = XorLinkedList::<i32>::default();
+
+        assert_empty_list(&list);
+    }
+
+    #[test]
+    fn is_empty_and_len() {
+        let mut list = new_list();
+
+        assert!(list.is_empty());
+        assert_eq!(list.len(), 0);
+
+        list.push_front(1);
+
+        assert!(!list.is_empty());
+        assert_eq!(list.len(), 1);
+    }
+
+    #[test]
+    fn contains_returns_false_on_empty_list() {
+        let list = new_list();
+
+        assert!(!list.contains(&1));
+    }
+
+    #[test]
+    fn contains_finds_existing_elements() {
+        let mut list = new_list();
+
+        list.push_back(1);
+        list.push_back(2);
+        list.push_back(3);
+
+        assert!(list.contains(&1));
+        assert!(list.contains(&3));
+        assert!(!list.contains(&4));
+    }
+
+    #[test]
+    fn front_and_back_on_empty_list() {
+        let mut list = new_list::<i32>();
+
+        assert!(list.front().is_none());
+        assert!(list.back().is_none());
+        assert!(list.front_mut().is_none());
+        assert!(list.back_mut().is_none());
+    }
+
+    #[test]
+    fn front_and_back_on_non_empty_list() {
+        let mut list = new_list();
+
+        list.push_back(10);
+        list.push_back(30);
+
+        assert_eq!(list.front(), Some(&10));
+        assert_eq!(list.back(), Some(&30));
+
+        if let Some(element) = list.front_mut() {
+            *element = 20;
+        }
+
+        if let Some(element) = list.back_mut() {
+            *element = 50;
+        }
+
+        assert_links(&list, &[20, 50]);
+    }
+
+    #[test]
+    fn clear_list() {
+        let mut list = new_list();
+
+        list.push_front(1);
+        list.push_front(2);
+        list.clear();
+
+        assert_empty_list(&list);
+    }
+
+    #[test]
+    fn display_output_on_empty_list() {
+        let list = new_list::<i32>();
+
+        assert_eq!(format!("{list}"), "[]");
+    }
+
+    #[test]
+    fn display_output_on_non_empty_list() {
+        let mut list = new_list();
+
+        list.push_front(1);
+
+        assert_eq!(format!("{list}"), "[1]");
+
+        list.push_front(2);
+
+        assert_eq!(format!("{list}"), "[2 <-> 1]");
+    }
+
+    #[test]
+    fn reverse_empty_list() {
+        let mut list = new_list::<i32>();
+
+        list.reverse();
+
+        assert_empty_list(&list);
+    }
+
+    #[test]
+    fn reverse_non_empty_list() {
+        let mut list = new_list();
+
+        list.push_front(1);
+        list.push_front(2);
+        list.push_front(3);
+        list.reverse();
+
+        assert_links(&list, &[1, 2, 3]);
+
+        list.push_back(4);
+        list.push_front(0);
+
+        assert_links(&list, &[0, 1, 2, 3, 4]);
+
+        list.reverse();
+
+        assert_links(&list, &[4, 3, 2, 1, 0]);
+    }
+
+    #[test]
+    fn split_list_with_even_size() {
+        let mut list = new_list();
+
+        list.push_back(1);
+        list.push_back(2);
+        list.push_back(3);
+        list.push_back(4);
+
+        let (first_list, second_list) = list.split();
+
+        assert_links(&first_list, &[1, 2]);
+        assert_links(&second_list, &[3, 4]);
+    }
+
+    #[test]
+    fn split_list_with_odd_size() {
+        let mut list = new_list();
+
+        list.push_back(1);
+        list.push_back(2);
+        list.push_back(3);
+
+        let (first_list, second_list) = list.split();
+
+        assert_links(&first_list, &[1, 2]);
+        assert_links(&second_list, &[3]);
+    }
+
+    #[test]
+    fn retain_list_even_elements() {
+        let mut list = new_list();
+
+        for x in 1..=6 {
+            list.push_back(x);
+        }
+
+        list.retain(|x| x % 2 == 0);
+
+        assert_links(&list, &[2, 4, 6]);
+    }
+
+    #[test]
+    fn retain_list_odd_elements() {
+        let mut list = new_list();
+
+        for x in 1..=6 {
+            list.push_back(x);
+        }
+
+        list.retain(|x| x % 2 == 1);
+
+        assert_links(&list, &[1, 3, 5]);
+    }
+
+    #[test]
+    fn retain_nothing() {
+        let mut list = new_list();
+
+        for x in 1..=3 {
+            list.push_back(x);
+        }
+
+        list.retain(|_| false);
+
+        assert_empty_list(&list);
+    }
+
+    #[test]
+    fn push_front_two_elements() {
+        let mut list = new_list();
+
+        list.push_front(1);
+
+        assert_links(&list, &[1]);
+
+        list.push_front(2);
+
+        assert_links(&list, &[2, 1]);
+    }
+
+    #[test]
+    fn push_back_two_elements() {
+        let mut list = new_list();
+
+        list.push_back(1);
+
+        assert_links(&list, &[1]);
+
+        list.push_back(2);
+
+        assert_links(&list, &[1, 2]);
+    }
+
+    #[test]
+    fn pop_on_empty_list() {
+        let mut list = new_list::<i32>();
+
+        assert!(list.pop_front().is_none());
+        assert!(list.pop_back().is_none());
+        assert_eq!(list.size, 0);
+    }
+
+    #[test]
+    fn pop_front_two_elements() {
+        let mut list = new_list();
+
+        list.push_front(1);
+        list.push_front(2);
+
+        assert_eq!(list.pop_front(), Some(2));
+
+        assert_links(&list, &[1]);
+
+        assert_eq!(list.pop_front(), Some(1));
+
+        assert_empty_list(&list);
+    }
+
+    #[test]
+    fn pop_back_two_elements() {
+        let mut list = new_list();
+
+        list.push_back(1);
+        list.push_back(2);
+
+        assert_eq!(list.pop_back(), Some(2));
+
+        assert_links(&list, &[1]);
+
+        assert_eq!(list.pop_back(), Some(1));
+
+        assert_empty_list(&list);
+    }
+
+    #[test]
+    fn iter_from_both_ends() {
+        let mut list = new_list();
+
+        for x in 1..=4 {
+            list.push_back(x);
+        }
+
+        let mut iter = list.iter();
+
+        assert_eq!(iter.next(), Some(&1));
+        assert_eq!(iter.next_back(), Some(&4));
+        assert_eq!(iter.next(), Some(&2));
+        assert_eq!(iter.next_back(), Some(&3));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+    }
+
+    #[test]
+    fn iter_mut_should_modify_elements() {
+        let mut list = new_list();
+
+        for x in 1..=3 {
+            list.push_back(x);
+        }
+
+        for x in &mut list {
+            *x *= 2;
+        }
+
+        for x in list.iter_mut().rev().take(1) {
+            *x += 1;
+        }
+
+        assert_links(&list, &[2, 4, 7]);
+    }
+
+    #[test]
+    fn into_iter_should_consume_list() {
+        let mut list = new_list();
+
+        for x in 1..=4 {
+            list.push_back(x);
+        }
+
+        let mut iter = list.into_iter();
+
+        assert_eq!(iter.next_back(), Some(4));
+
+        let collected: Vec<i32> = iter.collect();
+
+        assert_eq!(collected, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn for_loop_on_ref_list() {
+        let mut list = new_list();
+
+        list.push_back(1);
+        list.push_back(2);
+
+        let mut sum = 0;
+
+        for x in &list {
+            sum += *x;
+        }
+
+        assert_eq!(sum, 3);
+    }
+}
+
+mod invariants {
+    use super::{
+        InvariantViolation,
+        utils::{list_of, new_list},
+    };
+
+    #[test]
+    fn valid_lists_pass() {
+        assert_eq!(new_list::<i32>().check_invariants(), Ok(()));
+        assert_eq!(list_of([1]).check_invariants(), Ok(()));
+        assert_eq!(list_of([1, 2, 3]).check_invariants(), Ok(()));
+    }
+
+    #[test]
+    fn detects_head_with_previous() {
+        let list = list_of([1, 2]);
+        let mut head = list.head.expect("head should be Some");
+
+        unsafe { head.as_mut().previous = list.tail };
+
+        assert_eq!(
+            list.check_invariants(),
+            Err(InvariantViolation::HeadHasPrevious)
+        );
+
+        unsafe { head.as_mut().previous = None };
+    }
+
+    #[test]
+    fn detects_tail_with_next() {
+        let list = list_of([1, 2]);
+        let mut tail = list.tail.expect("tail should be Some");
+
+        unsafe { tail.as_mut().next = list.head };
+
+        assert_eq!(
+            list.check_invariants(),
+            Err(InvariantViolation::TailHasNext)
+        );
+
+        unsafe { tail.as_mut().next = None };
+    }
+
+    #[test]
+    fn detects_broken_back_link() {
+        let list = list_of([1, 2, 3]);
+        let mut tail = list.tail.expect("tail should be Some");
+        let original = unsafe { tail.as_ref().previous };
+
+        unsafe { tail.as_mut().previous = list.head };
+
+        assert_eq!(
+            list.check_invariants(),
+            Err(InvariantViolation::BrokenBackLink { index: 2 })
+        );
+
+        unsafe { tail.as_mut().previous = original };
+    }
+
+    #[test]
+    fn detects_cycle() {
+        let list = list_of([1, 2, 3, 4]);
+        let head = list.head.expect("head should be Some");
+        let mut second = unsafe { head.as_ref().next.expect("second node") };
+        let mut third = unsafe { second.as_ref().next.expect("third node") };
+        let original = unsafe { third.as_ref().next };
+
+        unsafe { third.as_mut().next = Some(second) };
+
+        assert_eq!(list.check_invariants(), Err(InvariantViolation::Cycle));
+
+        unsafe {
+            third.as_mut().next = original;
+            second.as_mut().previous = Some(head);
+        }
+
+        assert_eq!(list.check_invariants(), Ok(()));
+    }
+
+    #[test]
+    fn detects_tail_mismatch() {
+        let mut list = list_of([1, 2, 3]);
+        let original = list.tail;
+
+        list.tail = None;
+
+        assert_eq!(
+            list.check_invariants(),
+            Err(InvariantViolation::TailMismatch)
+        );
+
+        list.tail = original;
+    }
+
+    #[test]
+    fn detects_size_mismatch() {
+        let mut list = list_of([1, 2, 3]);
+
+        list.size = 5;
+
+        assert_eq!(
+            list.check_invariants(),
+            Err(InvariantViolation::SizeMismatch {
+                expected: 5,
+                found: 3
+            })
+        );
+
+        list.size = 3;
+    }
+
+    #[test]
+    fn mutating_methods_keep_invariants() {
+        let mut list = list_of([1, 2, 3, 4, 5, 6]);
+
+        list.reverse();
+        list.retain(|x| x % 2 == 0);
+        list.pop_front();
+        list.push_front(0);
+        list.pop_back();
+
+        assert_eq!(list.check_invariants(), Ok(()));
+
+        let (first_list, second_list) = list.split();
+
+        assert_eq!(first_list.check_invariants(), Ok(()));
+        assert_eq!(second_list.check_invariants(), Ok(()));
+    }
+
+    #[cfg(feature = "debug-checks")]
+    #[test]
+    fn push_checks_the_whole_list() {
+        use super::utils::expect_panic;
+
+        let mut list = list_of([1, 2, 3, 4, 5]);
+        let mut middle = unsafe { list.head.expect("head should be Some").as_ref().next }
+            .and_then(|node| unsafe { node.as_ref().next })
+            .expect("the list has a middle node");
+        let original = unsafe { middle.as_ref().previous };
+
+        unsafe { middle.as_mut().previous = list.head };
+
+        expect_panic(|| list.push_back(6));
+
+        unsafe { middle.as_mut().previous = original };
+
+        assert!(list.iter().eq(&[1, 2, 3, 4, 5, 6]));
+    }
+}
+
+mod model {
+    use std::{
+        collections::VecDeque,
+        mem,
+        panic::{self, AssertUnwindSafe},
+    };
+
+    use super::{
+        LinkedList,
+        utils::{Rng, live_allocations, new_list},
+    };
+
+    const SEEDS: u64 = 64;
+    const STEPS: usize = 200;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    enum Op {
+        PushFront(i32),
+        PushBack(i32),
+        PopFront,
+        PopBack,
+        RetainMultiplesOf(i32),
+        Reverse,
+        SplitKeepFirst,
+        SplitKeepSecond,
+        Clear,
+        AddToAll(i32),
+    }
+
+    fn random_op(rng: &mut Rng) -> Op {
+        match rng.below(20) {
+            0..=4 => Op::PushFront(rng.element()),
+            5..=9 => Op::PushBack(rng.element()),
+            10..=11 => Op::PopFront,
+            12..=13 => Op::PopBack,
+            14 => Op::RetainMultiplesOf(rng.element() % 3 + 1),
+            15 => Op::Reverse,
+            16 => Op::SplitKeepFirst,
+            17 => Op::SplitKeepSecond,
+            18 => {
+                if rng.below(4) == 0 {
+                    Op::Clear
+                } else {
+                    Op::Reverse
+                }
+            }
+            _ => Op::AddToAll(rng.element()),
+        }
+    }
+
+    /// Applies `op` to both containers and reports whether the values they
+    /// returned agree.
+    fn apply(
+        mut list: LinkedList<i32>,
+        model: &mut VecDeque<i32>,
+        op: Op,
+    ) -> (LinkedList<i32>, bool) {
+        let mut agreed = true;
+
+        match op {
+            Op::PushFront(element) => {
+                list.push_front(element);
+                model.push_front(element);
+            }
+            Op::PushBack(element) => {
+                list.push_back(element);
+                model.push_back(element);
+            }
+            Op::PopFront => agreed = list.pop_front() == model.pop_front(),
+            Op::PopBack => agreed = list.pop_back() == model.pop_back(),
+            Op::RetainMultiplesOf(divisor) => {
+                list.retain(|x| x % divisor == 0);
+                model.retain(|x| x % divisor == 0);
+            }
+            Op::Reverse => {
+                list.reverse();
+                model.make_contiguous().reverse();
+            }
+            Op::SplitKeepFirst => {
+                let mid = model.len().div_ceil(2);
+
+                model.truncate(mid);
+                list = list.split().0;
+            }
+            Op::SplitKeepSecond => {
+                let mid = model.len().div_ceil(2);
+
+                model.drain(..mid);
+                list = list.split().1;
+            }
+            Op::Clear => {
+                list.clear();
+                model.clear();
+            }
+            Op::AddToAll(amount) => {
+                for x in &mut list {
+                    *x += amount;
+                }
+
+                for x in model.iter_mut() {
+                    *x += amount;
+                }
+            }
+        }
+
+        (list, agreed)
+    }
+
+    fn matches(list: &LinkedList<i32>, model: &VecDeque<i32>) -> bool {
+        list.check_invariants().is_ok()
+            && list.len() == model.len()
+            && list.is_empty() == model.is_empty()
+            && list.front() == model.front()
+            && list.back() == model.back()
+            && list.iter().eq(model.iter())
+    }
+
+    /// Runs `ops` against both containers and returns the index of the first
+    /// step whose outcome differs from the model.
+    fn first_failure(ops: &[Op]) -> Option<usize> {
+        let mut list = new_list();
+        let mut model = VecDeque::new();
+
+        for (index, op) in ops.iter().enumerate() {
+            let Ok((next_list, agreed)) =
+                panic::catch_unwind(AssertUnwindSafe(|| apply(list, &mut model, *op)))
+            else {
+                return Some(index);
+            };
+
+            if !agreed || !matches(&next_list, &model) {
+                // A diverged list may have broken links, so it is leaked
+                // rather than dropped.
+                mem::forget(next_list);
+
+                return Some(index);
+            }
+
+            list = next_list;
+        }
+
+        None
+    }
+
+    /// Removes operations one at a time while `fails` still holds, repeating
+    /// until no single removal keeps the sequence failing.
+    fn shrink<F>(mut ops: Vec<Op>, fails: F) -> Vec<Op>
+    where
+        F: Fn(&[Op]) -> bool,
+    {
+        let mut shrunk = true;
+
+        while shrunk {
+            shrunk = false;
+
+            let mut index = 0;
+
+            while index < ops.len() {
+                let mut candidate = ops.clone();
+
+                candidate.remove(index);
+
+                if fails(&candidate) {
+                    ops = candidate;
+                    shrunk = true;
+                } else {
+                    index += 1;
+                }
+            }
+        }
+
+        ops
+    }
+
+    #[test]
+    fn random_operations_match_vec_deque() {
+        for seed in 0..SEEDS {
+            let mut rng = Rng(seed);
+            let ops: Vec<Op> = (0..STEPS).map(|_| random_op(&mut rng)).collect();
+
+            let before = live_allocations();
+
+            if let Some(index) = first_failure(&ops) {
+                let minimal = shrink(ops[..=index].to_vec(), |ops| first_failure(ops).is_some());
+
+                panic!("seed {seed} diverged from the model, minimal sequence: {minimal:?}");
+            }
+
+            assert_eq!(live_allocations(), before, "seed {seed} leaked allocations");
+        }
+    }
+
+    #[test]
+    fn rng_is_deterministic() {
+        let mut first = Rng(7);
+        let mut second = Rng(7);
+
+        for _ in 0..16 {
+            assert_eq!(first.next_u64(), second.next_u64());
+        }
+    }
+
+    #[test]
+    fn shrink_finds_minimal_sequence() {
+        let ops = vec![
+            Op::PushBack(1),
+            Op::Reverse,
+            Op::PushFront(2),
+            Op::Clear,
+            Op::PopBack,
+            Op::PushBack(3),
+        ];
+
+        let fails = |ops: &[Op]| {
+            ops.iter()
+                .position(|op| *op == Op::Reverse)
+                .is_some_and(|reverse| ops[reverse..].contains(&Op::PopBack))
+        };
+
+        assert_eq!(shrink(ops, fails), vec![Op::Reverse, Op::PopBack]);
+    }
+}
+
+mod drops {
+    use super::{
+        UnrolledLinkedList, XorLinkedList,
+        utils::{DropTracker, assert_empty_list, assert_no_leaks, expect_panic, new_list},
+    };
+
+    #[test]
+    fn push_and_drop() {
+        let tracker = DropTracker::new();
+
+        assert_no_leaks(|| {
+            let mut list = new_list();
+
+            for value in 0..5 {
+                list.push_back(tracker.track(value));
+                list.push_front(tracker.track(value));
+            }
+        });
+
+        assert_eq!(tracker.created(), 10);
+        tracker.assert_all_dropped_once();
+    }
+
+    #[test]
+    fn pop_and_clear() {
+        let tracker = DropTracker::new();
+
+        assert_no_leaks(|| {
+            let mut list = new_list();
+
+            for value in 0..6 {
+                list.push_back(tracker.track(value));
+            }
+
+            drop(list.pop_front());
+            drop(list.pop_back());
+
+            assert_eq!(tracker.dropped(), 2);
+
+            list.clear();
+
+            assert_eq!(tracker.dropped(), 6);
+        });
+
+        tracker.assert_all_dropped_once();
+    }
+
+    #[test]
+    fn reverse_and_iterate() {
+        let tracker = DropTracker::new();
+
+        assert_no_leaks(|| {
+            let mut list = new_list();
+
+            for value in 0..4 {
+                list.push_back(tracker.track(value));
+            }
+
+            list.reverse();
+
+            for element in &mut list {
+                element.value += 1;
+            }
+
+            assert!(list.contains(&tracker.track(4)));
+            assert_eq!(list.iter().map(|element| element.value).sum::<i32>(), 10);
+        });
+
+        tracker.assert_all_dropped_once();
+    }
+
+    #[test]
+    fn split() {
+        let tracker = DropTracker::new();
+
+        assert_no_leaks(|| {
+            let mut list = new_list();
+
+            for value in 0..5 {
+                list.push_back(tracker.track(value));
+            }
+
+            let (first_list, second_list) = list.split();
+
+            assert_eq!(first_list.len() + second_list.len(), 5);
+        });
+
+        assert_eq!(tracker.created(), 10);
+        tracker.assert_all_dropped_once();
+    }
+
+    #[test]
+    fn retain() {
+        let tracker = DropTracker::new();
+
+        assert_no_leaks(|| {
+            let mut list = new_list();
+
+            for value in 0..6 {
+                list.push_back(tracker.track(value));
+            }
+
+            list.retain(|element| element.value % 2 == 0);
+
+            assert_eq!(tracker.dropped(), 3);
+        });
+
+        tracker.assert_all_dropped_once();
+    }
+
+    #[test]
+    fn partially_consumed_into_iter() {
+        let tracker = DropTracker::new();
+
+        assert_no_leaks(|| {
+            let mut list = new_list();
+
+            for value in 0..5 {
+                list.push_back(tracker.track(value));
+            }
+
+            let mut iter = list.into_iter();
+
+            drop(iter.next());
+            drop(iter.next());
+        });
+
+        tracker.assert_all_dropped_once();
+    }
+
+    #[test]
+    fn panicking_drop_in_pop() {
+        let tracker = DropTracker::new();
+
+        assert_no_leaks(|| {
+            let mut list = new_list();
+
+            list.push_back(tracker.track_panicking(0));
+            list.push_back(tracker.track(1));
+
+            expect_panic(|| drop(list.pop_front()));
+
+            assert_eq!(list.len(), 1);
+            assert_eq!(list.check_invariants(), Ok(()));
+        });
+
+        tracker.assert_all_dropped_once();
+    }
+
+    #[test]
+    fn panicking_drop_in_clear() {
+        let tracker = DropTracker::new();
+
+        assert_no_leaks(|| {
+            let mut list = new_list();
+
+            list.push_back(tracker.track(0));
+            list.push_back(tracker.track_panicking(1));
+            list.push_back(tracker.track(2));
+
+            expect_panic(|| list.clear());
+
+            assert_eq!(tracker.dropped(), 3);
+            assert_empty_list(&list);
+        });
+
+        tracker.assert_all_dropped_once();
+    }
+
+    #[test]
+    fn panicking_drop_in_list_drop() {
+        let tracker = DropTracker::new();
+
+        assert_no_leaks(|| {
+            let mut list = new_list();
+
+            list.push_back(tracker.track(0));
+            list.push_back(tracker.track_panicking(1));
+            list.push_back(tracker.track(2));
+            list.push_back(tracker.track(3));
+
+            expect_panic(|| drop(list));
+        });
+
+        tracker.assert_all_dropped_once();
+    }
+
+    #[test]
+    fn panicking_drop_in_partially_consumed_into_iter() {
+        let tracker = DropTracker::new();
+
+        assert_no_leaks(|| {
+            let mut list = new_list();
+
+            list.push_back(tracker.track(0));
+            list.push_back(tracker.track(1));
+            list.push_back(tracker.track_panicking(2));
+            list.push_back(tracker.track(3));
+
+            let mut iter = list.into_iter();
+
+            drop(iter.next());
+
+            expect_panic(|| drop(iter));
+        });
+
+        tracker.assert_all_dropped_once();
+    }
+
+    #[test]
+    fn panicking_drop_of_last_element() {
+        let tracker = DropTracker::new();
+
+        assert_no_leaks(|| {
+            let mut list = new_list();
+
+            list.push_back(tracker.track(0));
+            list.push_back(tracker.track_panicking(1));
+
+            expect_panic(|| drop(list));
+        });
+
+        tracker.assert_all_dropped_once();
+    }
+
+    #[test]
+    fn panicking_drop_in_retain() {
+        let tracker = DropTracker::new();
+
+        assert_no_leaks(|| {
+            let mut list = new_list();
+
+            list.push_back(tracker.track(0));
+            list.push_back(tracker.track_panicking(1));
+            list.push_back(tracker.track(2));
+            list.push_back(tracker.track(3));
+
+            expect_panic(|| list.retain(|element| element.value % 2 == 0));
+
+            assert_eq!(list.len(), 3);
+            assert_eq!(list.check_invariants(), Ok(()));
+        });
+
+        tracker.assert_all_dropped_once();
+    }
+
+    #[test]
+    fn unrolled_list_operations() {
+        let tracker = DropTracker::new();
+
+        assert_no_leaks(|| {
+            let mut list = UnrolledLinkedList::<_, 3>::new();
+
+            for value in 0..10 {
+                list.push_back(tracker.track(value));
+            }
+
+            list.insert(4, tracker.track(10));
+            drop(list.remove(2));
+            drop(list.pop_front());
+            drop(list.pop_back());
+
+            let mut iter = list.into_iter();
+
+            drop(iter.next());
+        });
+
+        tracker.assert_all_dropped_once();
+    }
+
+    #[test]
+    fn xor_list_operations() {
+        let tracker = DropTracker::new();
+
+        assert_no_leaks(|| {
+            let mut list = XorLinkedList::new();
+
+            for value in 0..6 {
+                list.push_back(tracker.track(value));
+            }
+
+            list.retain(|element| element.value % 3 != 0);
+            list.reverse();
+            drop(list.pop_back());
+
+            let (first_list, _) = list.split();
+            let mut iter = first_list.into_iter();
+
+            drop(iter.next_back());
+        });
+
+        tracker.assert_all_dropped_once();
+    }
+}
+
+mod fuzzing {
+    use super::{super::fuzzing::fuzz_ops, utils::Rng};
+
+    #[test]
+    fn empty_input() {
+        fuzz_ops(&[]);
+    }
+
+    #[test]
+    fn every_operation() {
+        fuzz_ops(&[
+            1, 10, 0, 20, 1, 30, 11, 20, 5, 9, 3, 10, 7, 12, 1, 2, 3, 4, 1, 6, 0, 5, 7, 8,
+        ]);
+    }
+
+    #[test]
+    fn truncated_operand() {
+        fuzz_ops(&[1]);
+        fuzz_ops(&[0, 4, 1, 8, 12]);
+    }
+
+    #[test]
+    fn random_inputs() {
+        let mut rng = Rng(42);
+
+        for _ in 0..256 {
+            let length = usize::try_from(rng.below(128)).expect("length fits in usize");
+            let data: Vec<u8> = (0..length)
+                .map(|_| rng.next_u64().to_le_bytes()[0])
+                .collect();
+
+            fuzz_ops(&data);
+        }
+    }
+}
+
+mod parse {
+    use std::{error::Error, fmt, num::ParseIntError};
+
+    use super::{LinkedList, ParseListError, ParseListErrorKind, utils::new_list};
+
+    fn parse_i32(input: &str) -> Result<LinkedList<i32>, ParseListError<ParseIntError>> {
+        input.parse()
+    }
+
+    fn collect<T: Clone>(list: &LinkedList<T>) -> Vec<T> {
+        list.iter().cloned().collect()
+    }
+
+    fn round_trip(elements: &[&str]) {
+        let mut list = new_list();
+
+        for element in elements {
+            list.push_back((*element).to_string());
+        }
+
+        let rendered = list.to_string();
+        let parsed: LinkedList<String> = rendered.parse().expect("display output should parse");
+
+        assert_eq!(collect(&parsed), elements, "rendered as {rendered}");
+    }
+
+    #[test]
+    fn parse_empty_list() {
+        assert!(parse_i32("[]").expect("valid list").is_empty());
+        assert!(parse_i32("  [   ]  ").expect("valid list").is_empty());
+    }
+
+    #[test]
+    fn parse_display_output() {
+        let list = parse_i32("[1 <-> 2 <-> 3]").expect("valid list");
+
+        assert_eq!(collect(&list), vec![1, 2, 3]);
+        assert_eq!(list.check_invariants(), Ok(()));
+    }
+
+    #[test]
+    fn parse_tolerates_whitespace() {
+        let list = parse_i32(" [ 1<->2   <->\t3\n ] ").expect("valid list");
+
+        assert_eq!(collect(&list), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn round_trip_integers() {
+        let mut list = new_list();
+
+        for x in [-5, 0, 42] {
+            list.push_back(x);
+        }
+
+        let parsed = parse_i32(&list.to_string()).expect("display output should parse");
+
+        assert_eq!(collect(&parsed), vec![-5, 0, 42]);
+    }
+
+    #[test]
+    fn round_trip_strings_with_list_syntax() {
+        round_trip(&["a <-> b", "[nested]", "back\\slash", "<-", "->"]);
+        round_trip(&["  padded  ", "\t", "inner  space"]);
+        round_trip(&["x<->y", "]", "["]);
+    }
+
+    #[test]
+    fn round_trip_empty_strings() {
+        round_trip(&[""]);
+        round_trip(&["", "e", ""]);
+        round_trip(&[" ", "\\e"]);
+    }
+
+    #[test]
+    fn display_marks_empty_elements() {
+        let mut list = new_list();
+
+        list.push_back(String::new());
+
+        assert_eq!(list.to_string(), "[\\e]");
+
+        list.push_back("a".to_string());
+
+        assert_eq!(list.to_string(), "[\\e <-> a]");
+    }
+
+    #[test]
+    fn display_escapes_elements_written_in_pieces() {
+        struct Pieces(&'static [&'static str]);
+
+        impl fmt::Display for Pieces {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                self.0.iter().try_for_each(|piece| f.write_str(piece))
+            }
+        }
+
+        let mut list = new_list();
+
+        list.push_back(Pieces(&[" a <", "-", "> b ", " "]));
+        list.push_back(Pieces(&["<-", "x", "", "<"]));
+        list.push_back(Pieces(&["", ""]));
+
+        assert_eq!(list.to_string(), "[\\ a \\<-> b \\  <-> <-x< <-> \\e]");
+    }
+
+    #[test]
+    fn display_escapes_list_syntax() {
+        let mut list = new_list();
+
+        list.push_back("a <-> [b]".to_string());
+
+        assert_eq!(list.to_string(), "[a \\<-> \\[b\\]]");
+    }
+
+    #[test]
+    fn missing_opening_bracket() {
+        let error = parse_i32("  1 <-> 2]").expect_err("invalid list");
+
+        assert_eq!(error.position, 2);
+        assert_eq!(error.kind, ParseListErrorKind::MissingOpeningBracket);
+    }
+
+    #[test]
+    fn missing_closing_bracket() {
+        let error = parse_i32("[1 <-> 2").expect_err("invalid list");
+
+        assert_eq!(error.position, 8);
+        assert_eq!(error.kind, ParseListErrorKind::MissingClosingBracket);
+    }
+
+    #[test]
+    fn empty_element() {
+        let error = parse_i32("[1 <-> <-> 2]").expect_err("invalid list");
+
+        assert_eq!(error.position, 7);
+        assert_eq!(error.kind, ParseListErrorKind::EmptyElement);
+
+        let error = parse_i32("[1 <-> ]").expect_err("invalid list");
+
+        assert_eq!(error.position, 7);
+        assert_eq!(error.kind, ParseListErrorKind::EmptyElement);
+    }
+
+    #[test]
+    fn unexpected_bracket_and_trailing_characters() {
+        let error = parse_i32("[1 <-> [2]]").expect_err("invalid list");
+
+        assert_eq!(error.position, 7);
+        assert_eq!(error.kind, ParseListErrorKind::UnexpectedBracket);
+
+        let error = parse_i32("[1]  x").expect_err("invalid list");
+
+        assert_eq!(error.position, 5);
+        assert_eq!(error.kind, ParseListErrorKind::TrailingCharacters);
+    }
+
+    #[test]
+    fn dangling_escape() {
+        let error = "[a\\"
+            .parse::<LinkedList<String>>()
+            .expect_err("invalid list");
+
+        assert_eq!(error.position, 2);
+        assert_eq!(error.kind, ParseListErrorKind::DanglingEscape);
+    }
+
+    #[test]
+    fn element_error_reports_position_and_source() {
+        let error = parse_i32("[1 <->  x2 <-> 3]").expect_err("invalid list");
+
+        assert_eq!(error.position, 8);
+        assert!(matches!(error.kind, ParseListErrorKind::Element(_)));
+        assert!(error.source().is_some());
+        assert_eq!(
+            error.to_string(),
+            "invalid element: invalid digit found in string at position 8"
+        );
+    }
+}
+
+mod display_with {
+    use super::{
+        DisplayOptions,
+        utils::{list_of, new_list},
+    };
+
+    #[test]
+    fn default_options_match_display() {
+        let list = list_of([1, 2, 3]);
+
+        assert_eq!(
+            list.display_with(DisplayOptions::default()).to_string(),
+            list.to_string()
+        );
+        assert_eq!(
+            new_list::<i32>()
+                .display_with(DisplayOptions::default())
+                .to_string(),
+            "[]"
+        );
+    }
+
+    #[test]
+    fn custom_delimiters() {
+        let list = list_of([1, 2, 3]);
+        let options = DisplayOptions {
+            open: "(",
+            separator: ", ",
+            close: ")",
+            ..DisplayOptions::default()
+        };
+
+        assert_eq!(list.display_with(options).to_string(), "(1, 2, 3)");
+    }
+
+    #[test]
+    fn truncates_long_lists() {
+        let list = list_of([1, 2, 3, 4, 5]);
+        let options = |max_items| DisplayOptions {
+            max_items: Some(max_items),
+            ..DisplayOptions::default()
+        };
+
+        assert_eq!(
+            list.display_with(options(2)).to_string(),
+            "[1 <-> 2 <-> ...]"
+        );
+        assert_eq!(list.display_with(options(0)).to_string(), "[...]");
+        assert_eq!(
+            list.display_with(options(5)).to_string(),
+            "[1 <-> 2 <-> 3 <-> 4 <-> 5]"
+        );
+    }
+
+    #[test]
+    fn renders_back_to_front() {
+        let list = list_of([1, 2, 3, 4]);
+        let options = DisplayOptions {
+            reverse: true,
+            max_items: Some(3),
+            ..DisplayOptions::default()
+        };
+
+        assert_eq!(
+            list.display_with(options).to_string(),
+            "[4 <-> 3 <-> 2 <-> ...]"
+        );
+    }
+
+    #[test]
+    fn forwards_formatting_flags() {
+        let list = list_of([1, 22]);
+        let display = list.display_with(DisplayOptions::default());
+
+        assert_eq!(format!("{display:>3}"), "[  1 <->  22]");
+        assert_eq!(format!("{display:*<4}"), "[1*** <-> 22**]");
+
+        let mut floats = new_list();
+
+        floats.push_back(1.5);
+        floats.push_back(2.25);
+
+        let display = floats.display_with(DisplayOptions::default());
+
+        assert_eq!(format!("{display:.1}"), "[1.5 <-> 2.2]");
+    }
+
+    #[test]
+    fn alternate_prints_one_element_per_line() {
+        let list = list_of([10, 20, 30]);
+        let options = DisplayOptions {
+            max_items: Some(2),
+            ..DisplayOptions::default()
+        };
+
+        assert_eq!(
+            format!("{:#}", list.display_with(options)),
+            "[\n    0: 10\n    1: 20\n    ...\n]"
+        );
+
+        let options = DisplayOptions {
+            reverse: true,
+            ..DisplayOptions::default()
+        };
+
+        assert_eq!(
+            format!("{:#}", list.display_with(options)),
+            "[\n    2: 30\n    1: 20\n    0: 10\n]"
+        );
+    }
+}
+
+mod dot {
+    use super::{DotOptions, utils::new_list};
+
+    #[test]
+    fn empty_list() {
+        let list = new_list::<i32>();
+
+        assert_eq!(
+            list.to_dot(),
+            "digraph LinkedList {\n    rankdir=LR;\n    node [shape=record];\n    head [shape=plaintext];\n    tail [shape=plaintext];\n}\n"
+        );
+    }
+
+    #[test]
+    fn nodes_and_links() {
+        let mut list = new_list();
+
+        list.push_back(1);
+        list.push_back(2);
+
+        let dot = list.to_dot();
+
+        assert!(dot.contains("n0 [label=\"{<previous> null|<element> 1|<next> next}\"];"));
+        assert!(dot.contains("n1 [label=\"{<previous> previous|<element> 2|<next> null}\"];"));
+        assert!(dot.contains("n0:next -> n1:element;"));
+        assert!(dot.contains("n1:previous -> n0:element [style=dashed];"));
+        assert!(dot.contains("head -> n0;"));
+        assert!(dot.contains("tail -> n1;"));
+    }
+
+    #[test]
+    fn escapes_record_labels() {
+        let mut list = new_list();
+
+        list.push_back("{a|<b>}".to_string());
+
+        assert!(list.to_dot().contains("<element> \\{a\\|\\<b\\>\\}|"));
+    }
+
+    #[test]
+    fn shows_addresses() {
+        let mut list = new_list();
+
+        list.push_back(1);
+
+        let address = format!("{:p}", list.head.expect("head should be Some").as_ptr());
+        let mut output = vec![];
+
+        list.write_dot_with(
+            &mut output,
+            DotOptions {
+                show_addresses: true,
+            },
+        )
+        .expect("writing to a Vec<u8> cannot fail");
+
+        let dot = String::from_utf8(output).expect("DOT output is valid UTF-8");
+
+        assert!(dot.contains(&format!("<element> 1\\n{address}|")));
+        assert!(!list.to_dot().contains(&address));
+    }
+
+    #[test]
+    fn reveals_broken_back_link() {
+        let mut list = new_list();
+
+        list.push_back(1);
+        list.push_back(2);
+        list.push_back(3);
+
+        let mut tail = list.tail.expect("tail should be Some");
+        let original = unsafe { tail.as_ref().previous };
+
+        unsafe { tail.as_mut().previous = list.head };
+
+        let dot = list.to_dot();
+
+        unsafe { tail.as_mut().previous = original };
+
+        assert!(dot.contains("n2:previous -> n0:element [style=dashed];"));
+    }
+
+    #[test]
+    fn reveals_unreachable_tail() {
+        let mut list = new_list();
+
+        list.push_back(1);
+        list.push_back(2);
+
+        let original = list.tail;
+        let mut head = list.head.expect("head should be Some");
+
+        unsafe { head.as_mut().next = None };
+
+        let dot = list.to_dot();
+
+        unsafe { head.as_mut().next = original };
+
+        let tail = original.expect("tail should be Some");
+
+        assert!(dot.contains(&format!("x{tail:p} [label=\"unreachable\\n{tail:p}\"")));
+        assert!(dot.contains(&format!("tail -> x{tail:p};")));
+    }
+
+    #[test]
+    fn draws_cycles_once() {
+        let mut list = new_list();
+
+        for x in 0..5 {
+            list.push_back(x);
+        }
+
+        let mut tail = list.tail.expect("tail should be Some");
+        let second = unsafe { list.head.expect("head should be Some").as_ref().next };
+
+        unsafe { tail.as_mut().next = second };
+
+        let dot = list.to_dot();
+
+        unsafe { tail.as_mut().next = None };
+
+        assert_eq!(dot.matches("[label=\"{").count(), 5);
+        assert!(dot.contains("n4:next -> n1:element;"));
+        assert!(dot.contains("n4:previous -> n3:element [style=dashed];"));
+        assert!(dot.contains("tail -> n4;"));
+        assert!(!dot.contains("unreachable"));
+    }
+}
+
+mod diagram {
+    use super::{DiagramOptions, DiagramTrace, LinkedList, utils::new_list};
+
+    #[test]
+    fn empty_list() {
+        let list = new_list::<i32>();
+
+        assert_eq!(list.ascii_diagram(), "head -> NULL <- tail\n");
+    }
+
+    #[test]
+    fn single_node() {
+        let mut list = new_list();
+
+        list.push_back(7);
+
+        assert_eq!(
+            list.ascii_diagram(),
+            "        head/tail\n        +-------+\nNULL <- |   7   | -> NULL\n        +-------+\n"
+        );
+    }
+
+    #[test]
+    fn links_between_nodes() {
+        let mut list = new_list();
+
+        list.push_back(1);
+        list.push_back(22);
+
+        assert_eq!(
+            list.ascii_diagram(),
+            concat!(
+                "        head      tail\n",
+                "        +---+     +----+\n",
+                "NULL <- | 1 | <-> | 22 | -> NULL\n",
+                "        +---+     +----+\n",
+            )
+        );
+    }
+
+    #[test]
+    fn wraps_to_width() {
+        let mut list = new_list();
+
+        for element in 0..4 {
+            list.push_back(element);
+        }
+
+        let diagram = list.ascii_diagram_with(DiagramOptions { width: 20 });
+
+        assert!(diagram.lines().all(|line| line.chars().count() <= 20));
+        assert_eq!(
+            diagram.lines().filter(|line| line.contains("| ")).count(),
+            3
+        );
+        assert!(diagram.contains("<-> | 1 |"));
+        assert!(diagram.contains(" <-> | 3 | -> NULL\n"));
+    }
+
+    #[test]
+    fn reveals_broken_back_link() {
+        let mut list = new_list();
+
+        list.push_back(1);
+        list.push_back(2);
+        list.push_back(3);
+
+        let mut tail = list.tail.expect("tail should be Some");
+        let original = unsafe { tail.as_ref().previous };
+
+        unsafe { tail.as_mut().previous = list.head };
+
+        let diagram = list.ascii_diagram();
+
+        unsafe { tail.as_mut().previous = original };
+
+        assert!(diagram.contains("| 1 | <-> | 2 | --> | 3 |"));
+    }
+
+    #[test]
+    fn stops_at_cycle() {
+        let mut list = new_list();
+
+        list.push_back(1);
+        list.push_back(2);
+
+        let mut tail = list.tail.expect("tail should be Some");
+
+        unsafe { tail.as_mut().next = list.head };
+
+        let diagram = list.ascii_diagram();
+
+        unsafe { tail.as_mut().next = None };
+
+        assert!(diagram.contains("| 2 | --> ..."));
+    }
+
+    #[test]
+    fn trace_records_each_step() {
+        let mut list = new_list();
+        let mut trace = DiagramTrace::new(DiagramOptions::default(), true);
+
+        trace.step(&mut list, "push_back(1)", |list| list.push_back(1));
+
+        let popped = trace.step(&mut list, "pop_front()", LinkedList::pop_front);
+
+        assert_eq!(popped, Some(1));
+        assert_eq!(
+            trace.output(),
+            concat!(
+                "== push_back(1) ==\n",
+                "before:\n",
+                "head -> NULL <- tail\n",
+                "after:\n",
+                "        head/tail\n",
+                "        +-------+\n",
+                "NULL <- |   1   | -> NULL\n",
+                "        +-------+\n",
+                "== pop_front() ==\n",
+                "before:\n",
+                "        head/tail\n",
+                "        +-------+\n",
+                "NULL <- |   1   | -> NULL\n",
+                "        +-------+\n",
+                "after:\n",
+                "head -> NULL <- tail\n",
+            )
+        );
+    }
+
+    #[test]
+    fn trace_without_before() {
+        let mut list = new_list::<i32>();
+        let mut trace = DiagramTrace::new(DiagramOptions::default(), false);
+
+        trace.step(&mut list, "clear()", LinkedList::clear);
+
+        assert_eq!(trace.into_output(), "== clear() ==\nhead -> NULL <- tail\n");
+    }
+}
+
+mod journal {
+    use super::{
+        Journal, JournalEntry, ParseJournalError, ParseJournalErrorKind, RecordedList, ReplayError,
+        replay,
+        utils::{Rng, expect_panic},
+    };
+
+    #[test]
+    fn records_mutating_calls() {
+        let mut list = RecordedList::new();
+
+        list.push_back(1);
+        list.push_front(0);
+        list.push_back(2);
+        list.retain(|element| element % 2 == 0);
+        list.reverse();
+        assert_eq!(list.pop_back(), Some(0));
+
+        assert_eq!(
+            list.journal().entries,
+            vec![
+                JournalEntry::PushBack(1),
+                JournalEntry::PushFront(0),
+                JournalEntry::PushBack(2),
+                JournalEntry::Retain(vec![true, false, true]),
+                JournalEntry::Reverse,
+                JournalEntry::PopBack,
+            ]
+        );
+        assert_eq!(list.list().to_string(), "[2]");
+    }
+
+    #[test]
+    fn accessors_do_not_need_clone() {
+        struct NotClone;
+
+        let mut list = RecordedList::<NotClone>::default();
+
+        assert!(list.pop_front().is_none());
+        list.clear();
+
+        assert!(list.list().is_empty());
+        assert_eq!(list.journal().len(), 2);
+    }
+
+    #[test]
+    fn retain_records_outcome_when_predicate_panics() {
+        let mut list = RecordedList::new();
+
+        for x in 0..6 {
+            list.push_back(x);
+        }
+
+        expect_panic(|| {
+            list.retain(|element| {
+                assert!(*element < 3, "predicate failed");
+
+                element % 2 == 0
+            });
+        });
+
+        assert_eq!(
+            list.journal().entries.last(),
+            Some(&JournalEntry::Retain(vec![
+                true, false, true, true, true, true
+            ]))
+        );
+        assert_eq!(list.list().to_string(), "[0 <-> 2 <-> 3 <-> 4 <-> 5]");
+
+        let replayed = replay(list.journal()).expect("recorded journal should replay");
+
+        assert!(replayed.iter().eq(list.list().iter()));
+    }
+
+    #[test]
+    fn replay_rebuilds_random_sequences() {
+        for seed in 0..50 {
+            let mut rng = Rng(seed);
+            let mut list = RecordedList::new();
+
+            for _ in 0..100 {
+                match rng.below(9) {
+                    0 => list.push_front(rng.element()),
+                    1 | 2 => list.push_back(rng.element()),
+                    3 => drop(list.pop_front()),
+                    4 => drop(list.pop_back()),
+                    5 => {
+                        let divisor = rng.element() % 3 + 1;
+
+                        list.retain(|element| element % divisor == 0);
+                    }
+                    6 => list.reverse(),
+                    7 => drop(list.split_off_back()),
+                    _ => drop(list.split_off_front()),
+                }
+            }
+
+            let (list, journal) = list.into_parts();
+            let text = journal.to_string();
+            let parsed: Journal<i32> = text.parse().expect("journal text should parse");
+
+            assert_eq!(parsed, journal, "seed {seed}");
+
+            let replayed = replay(&parsed).expect("recorded journal should replay");
+
+            assert!(replayed.iter().eq(list.iter()), "seed {seed}");
+        }
+    }
+
+    #[test]
+    fn text_format() {
+        let mut list = RecordedList::new();
+
+        list.push_back("a b".to_string());
+        list.push_front("x\\\ny".to_string());
+        list.push_back(String::new());
+        list.retain(|element| !element.is_empty());
+        drop(list.split_off_front());
+        list.clear();
+
+        let text = list.journal().to_string();
+
+        assert_eq!(
+            text,
+            "push_back a b\npush_front x\\\\\\ny\npush_back \nretain 110\nkeep_back\nclear\n"
+        );
+        assert_eq!(
+            text.parse::<Journal<String>>()
+                .expect("journal text should parse"),
+            *list.journal()
+        );
+    }
+
+    #[test]
+    fn parse_errors() {
+        let error = |line, kind| Err(ParseJournalError { line, kind });
+
+        assert_eq!(
+            "push_back 1\n\nshuffle".parse::<Journal<i32>>(),
+            error(3, ParseJournalErrorKind::UnknownEntry)
+        );
+        assert_eq!(
+            "reverse now".parse::<Journal<i32>>(),
+            error(1, ParseJournalErrorKind::UnexpectedArgument)
+        );
+        assert_eq!(
+            "retain 102".parse::<Journal<i32>>(),
+            error(1, ParseJournalErrorKind::InvalidOutcome)
+        );
+        assert_eq!(
+            "push_back a\\t".parse::<Journal<String>>(),
+            Err(ParseJournalError {
+                line: 1,
+                kind: ParseJournalErrorKind::InvalidEscape
+            })
+        );
+        assert!(matches!(
+            "push_front x".parse::<Journal<i32>>(),
+            Err(ParseJournalError {
+                line: 1,
+                kind: ParseJournalErrorKind::Element(_)
+            })
+        ));
+    }
+
+    #[test]
+    fn replay_rejects_mismatched_retain() {
+        let journal: Journal<i32> = "push_back 1\nretain 10"
+            .parse()
+            .expect("journal should parse");
+
+        assert_eq!(
+            replay(&journal).map(|list| list.len()),
+            Err(ReplayError {
+                index: 1,
+                expected: 1,
+                found: 2
+            })
+        );
+    }
+}
+
+mod transaction {
+    use std::collections::VecDeque;
+
+    use super::utils::{DropTracker, Rng, assert_no_leaks, expect_panic, new_list};
+
+    #[test]
+    fn commit_keeps_edits() {
+        let mut list = new_list();
+
+        list.push_back(1);
+        list.push_back(2);
+        list.push_back(3);
+
+        let result: Result<i32, ()> = list.transaction(|tx| {
+            tx.push_front(0);
+
+            assert_eq!(tx.remove(2), Some(&2));
+
+            tx.insert(1, 9);
+
+            let back = *tx.pop_back().expect("list should not be empty");
+
+            assert_eq!(tx.len(), 3);
+
+            Ok(back)
+        });
+
+        assert_eq!(result, Ok(3));
+        assert_eq!(list.to_string(), "[0 <-> 9 <-> 1]");
+        assert_eq!(list.check_invariants(), Ok(()));
+    }
+
+    #[test]
+    fn error_restores_original_nodes() {
+        let mut list = new_list();
+
+        for element in 0..6 {
+            list.push_back(element);
+        }
+
+        let addresses: Vec<*const i32> = list.iter().map(|element| &raw const *element).collect();
+
+        let result: Result<(), &str> = list.transaction(|tx| {
+            tx.pop_front();
+            tx.push_back(10);
+            tx.remove(2);
+            tx.insert(0, 11);
+            tx.pop_back();
+            tx.pop_back();
+
+            Err("edit failed")
+        });
+
+        assert_eq!(result, Err("edit failed"));
+        assert_eq!(list.to_string(), "[0 <-> 1 <-> 2 <-> 3 <-> 4 <-> 5]");
+        assert_eq!(
+            list.iter()
+                .map(|element| &raw const *element)
+                .collect::<Vec<_>>(),
+            addresses
+        );
+        assert_eq!(list.check_invariants(), Ok(()));
+    }
+
+    #[test]
+    fn panic_restores_original_nodes() {
+        let mut list = new_list();
+
+        list.push_back(1);
+        list.push_back(2);
+
+        expect_panic(|| {
+            let _: Result<(), ()> = list.transaction(|tx| {
+                tx.pop_front();
+                tx.pop_front();
+                tx.push_back(3);
+
+                panic!("edit failed");
+            });
+        });
+
+        assert_eq!(list.to_string(), "[1 <-> 2]");
+        assert_eq!(list.check_invariants(), Ok(()));
+    }
+
+    #[test]
+    fn drops_elements_once() {
+        let tracker = DropTracker::new();
+
+        assert_no_leaks(|| {
+            let mut list = new_list();
+
+            list.push_back(tracker.track(0));
+            list.push_back(tracker.track(1));
+
+            let _: Result<(), ()> = list.transaction(|tx| {
+                tx.push_back(tracker.track(2));
+                tx.pop_front();
+
+                Err(())
+            });
+
+            // Only the inserted element is gone after a rollback.
+            assert_eq!(tracker.dropped(), 1);
+
+            let _: Result<(), ()> = list.transaction(|tx| {
+                tx.push_back(tracker.track(3));
+                tx.pop_front();
+
+                assert_eq!(tracker.dropped(), 1);
+
+                Ok(())
+            });
+
+            // The removed element is dropped on commit.
+            assert_eq!(tracker.dropped(), 2);
+            assert!(list.iter().map(|element| element.value).eq([1, 3]));
+        });
+
+        assert_eq!(tracker.created(), 4);
+        tracker.assert_all_dropped_once();
+    }
+
+    #[test]
+    fn matches_model() {
+        for seed in 0..100 {
+            let mut rng = Rng(seed);
+            let mut list = new_list();
+            let mut model = VecDeque::new();
+
+            for _ in 0..rng.below(10) {
+                let element = rng.element();
+
+                list.push_back(element);
+                model.push_back(element);
+            }
+
+            for _ in 0..5 {
+                let mut edited = model.clone();
+                let commit = rng.below(2) == 0;
+
+                let result = list.transaction(|tx| {
+                    for _ in 0..rng.below(20) {
+                        match rng.below(5) {
+                            0 => {
+                                let element = rng.element();
+
+                                tx.push_front(element);
+                                edited.push_front(element);
+                            }
+                            1 => {
+                                let element = rng.element();
+
+                                tx.push_back(element);
+                                edited.push_back(element);
+                            }
+                            2 => assert_eq!(tx.pop_front(), edited.pop_front().as_ref()),
+                            3 => {
+                                let index = usize::try_from(rng.below(tx.len() as u64 + 1))
+                                    .expect("index fits in usize");
+                                let element = rng.element();
+
+                                tx.insert(index, element);
+                                edited.insert(index, element);
+                            }
+                            _ => {
+                                let index = usize::try_from(rng.below(tx.len() as u64 + 1))
+                                    .expect("index fits in usize");
+
+                                assert_eq!(tx.remove(index), edited.remove(index).as_ref());
+                            }
+                        }
+
+                        assert!(tx.iter().eq(edited.iter()), "seed {seed}");
+                    }
+
+                    if commit { Ok(()) } else { Err(()) }
+                });
+
+                if result.is_ok() {
+                    model = edited;
+                }
+
+                assert_eq!(list.check_invariants(), Ok(()), "seed {seed}");
+                assert!(list.iter().eq(model.iter()), "seed {seed}");
+            }
+        }
+    }
+}
+
+mod persistent {
+    use super::{
+        LinkedList, PersistentList,
+        utils::{DropTracker, assert_no_leaks, live_allocations, new_list},
+    };
+
+    #[test]
+    fn cons_shares_the_tail() {
+        let empty = PersistentList::new();
+        let one = empty.cons(1);
+        let two = one.cons(2);
+        let other = one.push_front(3);
+
+        assert!(empty.is_empty());
+        assert_eq!(one.len(), 1);
+        assert_eq!(two.head(), Some(&2));
+        assert_eq!(other.head(), Some(&3));
+        assert!(two.iter().eq([2, 1].iter()));
+        assert!(other.iter().eq([3, 1].iter()));
+
+        let two_tail = two.tail().expect("list should not be empty");
+        let other_tail = other.tail().expect("list should not be empty");
+
+        assert!(two_tail.ptr_eq(&one));
+        assert!(other_tail.ptr_eq(&one));
+        assert_eq!(two_tail.len(), 1);
+        assert!(empty.tail().is_none());
+    }
+
+    #[test]
+    fn clone_does_not_allocate() {
+        let list = (0..100).fold(PersistentList::new(), |list, element| list.cons(element));
+        let before = live_allocations();
+        let copy = list.clone();
+
+        assert_eq!(live_allocations(), before);
+        assert!(copy.ptr_eq(&list));
+        assert_eq!(copy.len(), 100);
+    }
+
+    #[test]
+    fn converts_to_and_from_linked_list() {
+        let mut list = new_list();
+
+        for element in 0..5 {
+            list.push_back(element);
+        }
+
+        let persistent = PersistentList::from(list);
+
+        assert!(persistent.iter().eq([0, 1, 2, 3, 4].iter()));
+        assert_eq!(persistent.iter().size_hint(), (5, Some(5)));
+        assert_eq!(format!("{persistent:?}"), "[0, 1, 2, 3, 4]");
+
+        let list = LinkedList::from(&persistent);
+
+        assert_eq!(list.to_string(), "[0 <-> 1 <-> 2 <-> 3 <-> 4]");
+        assert_eq!(list.check_invariants(), Ok(()));
+    }
+
+    #[test]
+    fn dropping_a_version_keeps_shared_nodes() {
+        let tracker = DropTracker::new();
+
+        assert_no_leaks(|| {
+            let base = PersistentList::new()
+                .cons(tracker.track(0))
+                .cons(tracker.track(1));
+            let extended = base.cons(tracker.track(2)).cons(tracker.track(3));
+
+            drop(extended);
+
+            assert_eq!(tracker.dropped(), 2);
+            assert!(base.iter().map(|element| element.value).eq([1, 0]));
+        });
+
+        assert_eq!(tracker.created(), 4);
+        tracker.assert_all_dropped_once();
+    }
+
+    #[test]
+    fn drops_long_chain_without_overflow() {
+        let mut list = PersistentList::new();
+
+        for element in 0..1_000_000 {
+            list = list.cons(element);
+        }
+
+        assert_eq!(list.len(), 1_000_000);
+
+        drop(list);
+    }
+}
+
+mod cow {
+    use std::thread;
+
+    use super::{
+        CowList,
+        utils::{live_allocations, new_list},
+    };
+
+    fn cow_list(elements: &[i32]) -> CowList<i32> {
+        let mut list = new_list();
+
+        for element in elements {
+            list.push_back(*element);
+        }
+
+        CowList::from(list)
+    }
+
+    #[test]
+    fn clone_shares_the_list() {
+        let list = cow_list(&[1, 2, 3]);
+
+        assert!(list.is_unique());
+
+        let before = live_allocations();
+        let copy = list.clone();
+
+        assert_eq!(live_allocations(), before);
+        assert!(copy.ptr_eq(&list));
+        assert!(!list.is_unique());
+        assert_eq!(copy.len(), 3);
+        assert_eq!(copy.to_string(), "[1 <-> 2 <-> 3]");
+
+        drop(copy);
+
+        assert!(list.is_unique());
+    }
+
+    #[test]
+    fn first_write_copies_a_shared_list() {
+        let mut list = cow_list(&[1, 2, 3, 4]);
+        let snapshot = list.clone();
+
+        list.retain(|element| element % 2 == 0);
+
+        assert!(!list.ptr_eq(&snapshot));
+        assert!(list.is_unique());
+        assert!(snapshot.is_unique());
+        assert_eq!(list.to_string(), "[2 <-> 4]");
+        assert_eq!(snapshot.to_string(), "[1 <-> 2 <-> 3 <-> 4]");
+        assert_eq!(list.check_invariants(), Ok(()));
+    }
+
+    #[test]
+    fn unique_list_is_mutated_in_place() {
+        let mut list = cow_list(&[1, 2]);
+        let before = live_allocations();
+
+        list.reverse();
+
+        for element in &mut list {
+            *element *= 10;
+        }
+
+        if let Some(front) = list.front_mut() {
+            *front += 1;
+        }
+
+        assert_eq!(list.pop_back(), Some(10));
+        assert_eq!(live_allocations(), before - 1);
+        assert_eq!(list.to_string(), "[21]");
+    }
+
+    #[test]
+    fn make_mut_and_into_list() {
+        let mut list = cow_list(&[1]);
+        let snapshot = list.clone();
+
+        list.make_mut().push_back(2);
+        list.push_front(0);
+
+        assert_eq!(list.clone().into_list().to_string(), "[0 <-> 1 <-> 2]");
+        assert_eq!(snapshot.into_list().to_string(), "[1]");
+    }
+
+    #[test]
+    fn clear_releases_a_shared_list() {
+        let mut list = cow_list(&[1, 2]);
+        let snapshot = list.clone();
+
+        list.clear();
+
+        assert!(list.is_empty());
+        assert_eq!(snapshot.len(), 2);
+    }
+
+    #[test]
+    fn readers_share_across_threads() {
+        let list = cow_list(&[1, 2, 3]);
+
+        thread::scope(|scope| {
+            for _ in 0..4 {
+                let reader = list.clone();
+
+                scope.spawn(move || assert_eq!(reader.iter().sum::<i32>(), 6));
+            }
+        });
+
+        assert!(list.is_unique());
+    }
+}
+
+mod concurrent_queue {
+    use std::thread;
+
+    use super::{
+        ConcurrentQueue,
+        utils::{DropTracker, assert_no_leaks},
+    };
+
+    #[test]
+    fn fifo_order() {
+        let queue = ConcurrentQueue::new();
+        let mut consumer = queue.consumer().expect("no other consumer exists");
+
+        assert_eq!(consumer.pop(), None);
+
+        queue.push(1);
+        queue.push(2);
+
+        assert_eq!(consumer.pop(), Some(1));
+
+        queue.push(3);
+
+        assert_eq!(consumer.pop(), Some(2));
+        assert_eq!(consumer.pop(), Some(3));
+        assert_eq!(consumer.pop(), None);
+    }
+
+    #[test]
+    fn single_consumer_at_a_time() {
+        let queue = ConcurrentQueue::<i32>::new();
+        let consumer = queue.consumer();
+
+        assert!(consumer.is_some());
+        assert!(queue.consumer().is_none());
+
+        drop(consumer);
+
+        assert!(queue.consumer().is_some());
+    }
+
+    #[test]
+    fn drop_frees_remaining_elements() {
+        let tracker = DropTracker::new();
+
+        assert_no_leaks(|| {
+            let queue = ConcurrentQueue::new();
+
+            for value in 0..5 {
+                queue.push(tracker.track(value));
+            }
+
+            let mut consumer = queue.consumer().expect("no other consumer exists");
+
+            assert_eq!(consumer.pop().map(|element| element.value), Some(0));
+        });
+
+        assert_eq!(tracker.created(), 5);
+        tracker.assert_all_dropped_once();
+    }
+
+    #[test]
+    fn stress_many_producers() {
+        const PRODUCERS: usize = 8;
+        const PER_PRODUCER: usize = 20_000;
+
+        let queue = ConcurrentQueue::new();
+
+        thread::scope(|scope| {
+            for producer in 0..PRODUCERS {
+                let queue = &queue;
+
+                scope.spawn(move || {
+                    for sequence in 0..PER_PRODUCER {
+                        queue.push((producer, sequence));
+                    }
+                });
+            }
+
+            let mut consumer = queue.consumer().expect("no other consumer exists");
+            let mut next_sequence = [0; PRODUCERS];
+            let mut received = 0;
+
+            while received < PRODUCERS * PER_PRODUCER {
+                let Some((producer, sequence)) = consumer.pop() else {
+                    thread::yield_now();
+
+                    continue;
+                };
+
+                // Each producer's elements arrive in order, so any loss or
+                // duplicate shows up as a gap or a repeat.
+                assert_eq!(sequence, next_sequence[producer], "producer {producer}");
+
+                next_sequence[producer] += 1;
+                received += 1;
+            }
+
+            assert_eq!(consumer.pop(), None);
+            assert!(next_sequence.iter().all(|count| *count == PER_PRODUCER));
+        });
+    }
+}
+
+mod locking_list {
+    use std::thread;
+
+    use super::{
+        LockingList,
+        utils::{DropTracker, assert_no_leaks},
+    };
+
+    #[test]
+    fn keeps_elements_sorted() {
+        let list = LockingList::new();
+
+        for element in [5, 1, 4, 1, 3] {
+            list.insert_sorted(element);
+        }
+
+        assert_eq!(list.len(), 5);
+        assert_eq!(list.snapshot().to_string(), "[1 <-> 1 <-> 3 <-> 4 <-> 5]");
+        assert!(list.contains(&4));
+        assert!(!list.contains(&2));
+        assert!(!list.contains(&6));
+
+        assert_eq!(list.remove(&1), Some(1));
+        assert_eq!(list.remove(&5), Some(5));
+        assert_eq!(list.remove(&2), None);
+        assert_eq!(list.remove(&6), None);
+
+        assert_eq!(list.len(), 3);
+        assert_eq!(list.snapshot().to_string(), "[1 <-> 3 <-> 4]");
+    }
+
+    #[test]
+    fn drop_frees_elements() {
+        let tracker = DropTracker::new();
+
+        assert_no_leaks(|| {
+            let list = LockingList::new();
+
+            for value in [3, 1, 2] {
+                list.insert_sorted(tracker.track(value));
+            }
+
+            let removed = list.remove(&tracker.track(2));
+
+            assert_eq!(removed.map(|element| element.value), Some(2));
+        });
+
+        assert_eq!(tracker.created(), 4);
+        tracker.assert_all_dropped_once();
+    }
+
+    #[test]
+    fn concurrent_edits_keep_invariants() {
+        const THREADS: usize = 8;
+        const PER_THREAD: usize = 500;
+
+        let list = LockingList::new();
+
+        thread::scope(|scope| {
+            for thread in 0..THREADS {
+                let list = &list;
+
+                scope.spawn(move || {
+                    // Interleaved values spread every thread over the whole
+                    // list.
+                    for index in 0..PER_THREAD {
+                        list.insert_sorted(index * THREADS + thread);
+                    }
+
+                    for index in (0..PER_THREAD).step_by(2) {
+                        let value = index * THREADS + thread;
+
+                        assert!(list.contains(&value));
+                        assert_eq!(list.remove(&value), Some(value));
+                    }
+                });
+            }
+        });
+
+        let snapshot = list.snapshot();
+        let expected = (0..THREADS * PER_THREAD).filter(|value| (value / THREADS) % 2 == 1);
+
+        assert_eq!(list.len(), THREADS * PER_THREAD / 2);
+        assert_eq!(snapshot.len(), list.len());
+        assert!(snapshot.iter().copied().eq(expected));
+        assert_eq!(snapshot.check_invariants(), Ok(()));
+    }
+}
+
+mod blocking_channel {
+    use std::{thread, time::Duration};
+
+    use super::{
+        RecvError, RecvTimeoutError, SendError, TryRecvError, TrySendError, channel,
+        utils::DropTracker,
+    };
+
+    #[test]
+    fn delivers_in_order() {
+        let (sender, receiver) = channel(None);
+
+        for element in 0..5 {
+            sender.send(element).expect("receiver is alive");
+        }
+
+        assert_eq!(receiver.recv(), Ok(0));
+        assert!(receiver.try_iter().eq(1..5));
+        assert_eq!(receiver.try_recv(), Err(TryRecvError::Empty));
+
+        drop(sender);
+
+        assert_eq!(receiver.try_recv(), Err(TryRecvError::Disconnected));
+        assert_eq!(receiver.recv(), Err(RecvError));
+    }
+
+    #[test]
+    fn bounded_channel_blocks_when_full() {
+        let (sender, receiver) = channel(Some(2));
+
+        sender.try_send(1).expect("channel has room");
+        sender.try_send(2).expect("channel has room");
+
+        assert_eq!(sender.try_send(3), Err(TrySendError::Full(3)));
+
+        thread::scope(|scope| {
+            scope.spawn(|| sender.send(3).expect("receiver is alive"));
+
+            assert_eq!(receiver.recv(), Ok(1));
+        });
+
+        assert!(receiver.try_iter().eq([2, 3]));
+    }
+
+    #[test]
+    fn detects_disconnected_receiver() {
+        let tracker = DropTracker::new();
+        let (sender, receiver) = channel(Some(1));
+
+        assert!(sender.send(tracker.track(0)).is_ok());
+
+        drop(receiver);
+
+        // The queued element is dropped with the receiver.
+        assert_eq!(tracker.dropped(), 1);
+        assert!(matches!(
+            sender.send(tracker.track(1)),
+            Err(SendError(element)) if element.value == 1
+        ));
+        assert!(matches!(
+            sender.try_send(tracker.track(2)),
+            Err(TrySendError::Disconnected(element)) if element.value == 2
+        ));
+    }
+
+    #[test]
+    fn receiver_wakes_blocked_sender_on_drop() {
+        let (sender, receiver) = channel(Some(1));
+
+        sender.send(0).expect("receiver is alive");
+
+        thread::scope(|scope| {
+            let blocked = scope.spawn(|| sender.send(1));
+
+            thread::sleep(Duration::from_millis(20));
+            drop(receiver);
+
+            assert_eq!(
+                blocked.join().expect("sender should not panic"),
+                Err(SendError(1))
+            );
+        });
+    }
+
+    #[test]
+    fn recv_timeout() {
+        let (sender, receiver) = channel(None);
+
+        assert_eq!(
+            receiver.recv_timeout(Duration::from_millis(10)),
+            Err(RecvTimeoutError::Timeout)
+        );
+
+        thread::scope(|scope| {
+            scope.spawn(|| {
+                thread::sleep(Duration::from_millis(10));
+                sender.send(7).expect("receiver is alive");
+            });
+
+            assert_eq!(receiver.recv_timeout(Duration::from_secs(10)), Ok(7));
+        });
+
+        drop(sender);
+
+        assert_eq!(
+            receiver.recv_timeout(Duration::from_secs(10)),
+            Err(RecvTimeoutError::Disconnected)
+        );
+    }
+
+    #[test]
+    fn iterator_ends_when_senders_are_gone() {
+        const SENDERS: usize = 4;
+        const PER_SENDER: usize = 1_000;
+
+        let (sender, receiver) = channel(Some(16));
+
+        thread::scope(|scope| {
+            for index in 0..SENDERS {
+                let sender = sender.clone();
+
+                scope.spawn(move || {
+                    for element in 0..PER_SENDER {
+                        sender
+                            .send(index * PER_SENDER + element)
+                            .expect("receiver is alive");
+                    }
+                });
+            }
+
+            drop(sender);
+
+            let mut elements: Vec<_> = receiver.into_iter().collect();
+
+            elements.sort_unstable();
+
+            assert!(elements.into_iter().eq(0..SENDERS * PER_SENDER));
+        });
+    }
+}
+
+mod parallel_ops {
+    use super::{
+        parallel,
+        utils::{expect_panic, list_of, new_list},
+    };
+
+    #[test]
+    fn for_each_mut_visits_every_element_in_place() {
+        let mut list = list_of(0..10_000);
+
+        list.par_for_each_mut(|element| *element *= 2);
+
+        assert!(
+            list.iter()
+                .copied()
+                .eq((0..10_000).map(|element| element * 2))
+        );
+        assert_eq!(list.check_invariants(), Ok(()));
+    }
+
+    #[test]
+    fn segments_are_relinked_in_order() {
+        for count in [2, 3, 7, 64] {
+            let mut list = list_of(0..50);
+
+            parallel::for_each_in_segments(&mut list, count, |segment| {
+                assert!(segment.len() <= 50_usize.div_ceil(count));
+                segment.iter_mut().for_each(|element| *element += 1);
+            });
+
+            assert!(list.iter().copied().eq(1..51));
+            assert_eq!(list.check_invariants(), Ok(()));
+        }
+    }
+
+    #[test]
+    fn map_into_vec_keeps_list_order() {
+        let list = list_of(0..10_000);
+
+        assert!(
+            list.par_map_into_vec(|element| i64::from(*element) + 1)
+                .into_iter()
+                .eq(1..10_001)
+        );
+
+        for count in [1, 4, 13] {
+            assert_eq!(
+                parallel::map_in_segments(&list_of(0..30), count, |element| element * 3),
+                (0..30).map(|element| element * 3).collect::<Vec<_>>()
+            );
+        }
+
+        assert!(
+            new_list::<i32>()
+                .par_map_into_vec(|element| *element)
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn retain_matches_sequential_retain() {
+        let mut list = list_of(0..10_000);
+        let mut expected = list_of(0..10_000);
+
+        list.par_retain(|element| element % 3 != 0);
+        expected.retain(|element| element % 3 != 0);
+
+        assert!(list.iter().eq(expected.iter()));
+        assert_eq!(list.check_invariants(), Ok(()));
+
+        let mut list = list_of(0..40);
+
+        parallel::for_each_in_segments(&mut list, 4, |segment| {
+            segment.retain(|element| (10..30).contains(element));
+        });
+
+        assert!(list.iter().copied().eq(10..30));
+        assert_eq!(list.check_invariants(), Ok(()));
+    }
+
+    #[test]
+    fn retain_can_empty_every_segment() {
+        let mut list = list_of(0..40);
+
+        parallel::for_each_in_segments(&mut list, 4, |segment| segment.retain(|_| false));
+
+        assert!(list.is_empty());
+        assert_eq!(list.check_invariants(), Ok(()));
+    }
+
+    #[test]
+    fn panicking_worker_leaves_list_intact() {
+        let mut list = list_of(0..40);
+
+        expect_panic(|| {
+            parallel::for_each_in_segments(&mut list, 4, |segment| {
+                segment.iter_mut().for_each(|element| *element += 100);
+
+                assert!(
+                    !segment.iter().any(|element| *element == 125),
+                    "worker failed"
+                );
+            });
+        });
+
+        assert_eq!(list.len(), 40);
+        assert!(list.iter().copied().eq(100..140));
+        assert_eq!(list.check_invariants(), Ok(()));
+    }
+}
+
+mod par_sort {
+    use std::{
+        cmp::Ordering,
+        sync::atomic::{self, AtomicUsize},
+    };
+
+    use super::{
+        parallel,
+        utils::{Rng, expect_panic, list_of},
+    };
+
+    fn random_pairs(rng: &mut Rng, len: usize) -> Vec<(u64, usize)> {
+        (0..len).map(|index| (rng.below(8), index)).collect()
+    }
+
+    fn by_key(left: &(u64, usize), right: &(u64, usize)) -> Ordering {
+        left.0.cmp(&right.0)
+    }
+
+    #[test]
+    fn sorts_stably_for_any_segment_count() {
+        let mut rng = Rng(11);
+
+        for len in [0, 1, 2, 3, 7, 64, 257] {
+            let elements = random_pairs(&mut rng, len);
+            let mut expected = elements.clone();
+
+            expected.sort_by(by_key);
+
+            for count in [1, 2, 3, 5, 8, 300] {
+                let mut list = list_of(elements.iter().copied());
+
+                parallel::sort_in_segments(&mut list, count, by_key);
+
+                assert!(list.iter().eq(expected.iter()), "len {len}, count {count}");
+                assert_eq!(list.len(), len);
+                assert_eq!(list.check_invariants(), Ok(()));
+            }
+        }
+    }
+
+    #[test]
+    fn sorts_large_lists() {
+        let mut rng = Rng(3);
+        let elements: Vec<i32> = (0..10_000).map(|_| rng.element()).collect();
+        let mut expected = elements.clone();
+        let mut list = list_of(elements.iter().copied());
+
+        expected.sort_unstable();
+        list.par_sort_by(i32::cmp);
+
+        assert!(list.iter().eq(expected.iter()));
+        assert_eq!(list.check_invariants(), Ok(()));
+
+        list.par_sort_by(|left, right| right.cmp(left));
+
+        assert!(list.iter().eq(expected.iter().rev()));
+    }
+
+    #[test]
+    fn panicking_comparator_keeps_every_element() {
+        for count in [1, 4] {
+            let elements: Vec<i32> = (0..50).rev().collect();
+            let mut list = list_of(elements.iter().copied());
+            let calls = AtomicUsize::new(0);
+
+            expect_panic(|| {
+                parallel::sort_in_segments(&mut list, count, |left: &i32, right: &i32| {
+                    let calls = calls.fetch_add(1, atomic::Ordering::Relaxed);
+
+                    assert!(calls < 60, "comparator failed");
+
+                    left.cmp(right)
+                });
+            });
+
+            let mut remaining: Vec<i32> = list.iter().copied().collect();
+
+            remaining.sort_unstable();
+
+            assert!(remaining.into_iter().eq(0..50), "count {count}");
+            assert_eq!(list.check_invariants(), Ok(()));
+        }
+    }
+}
+
+mod external_sort {
+    use std::{
+        cell::Cell,
+        io::{self, Read, Write},
+    };
+
+    use super::{
+        LinkedList, RunCodec,
+        utils::{Rng, TempDir},
+    };
+
+    struct PairCodec;
+
+    impl RunCodec<(i64, u64)> for PairCodec {
+        fn encode<W: Write>(&self, element: &(i64, u64), writer: &mut W) -> io::Result<()> {
+            writer.write_all(&element.0.to_le_bytes())?;
+            writer.write_all(&element.1.to_le_bytes())
+        }
+
+        fn decode<R: Read>(&self, reader: &mut R) -> io::Result<(i64, u64)> {
+            let mut key = [0; 8];
+            let mut index = [0; 8];
+
+            reader.read_exact(&mut key)?;
+            reader.read_exact(&mut index)?;
+
+            Ok((i64::from_le_bytes(key), u64::from_le_bytes(index)))
+        }
+    }
+
+    /// Fails to decode once `limit` elements have been read back, as if the
+    /// run files were corrupted.
+    struct BrokenCodec {
+        limit: usize,
+        decoded: Cell<usize>,
+    }
+
+    impl RunCodec<(i64, u64)> for BrokenCodec {
+        fn encode<W: Write>(&self, element: &(i64, u64), writer: &mut W) -> io::Result<()> {
+            PairCodec.encode(element, writer)
+        }
+
+        fn decode<R: Read>(&self, reader: &mut R) -> io::Result<(i64, u64)> {
+            if self.decoded.get() == self.limit {
+                return Err(io::Error::new(io::ErrorKind::InvalidData, "corrupted run"));
+            }
+
+            self.decoded.set(self.decoded.get() + 1);
+
+            PairCodec.decode(reader)
+        }
+    }
+
+    fn random_pairs(len: u64) -> Vec<(i64, u64)> {
+        let mut rng = Rng(5);
+
+        (0..len)
+            .map(|index| (i64::from(rng.element() % 16), index))
+            .collect()
+    }
+
+    fn by_key(left: &(i64, u64), right: &(i64, u64)) -> std::cmp::Ordering {
+        left.0.cmp(&right.0)
+    }
+
+    #[test]
+    fn sorts_stably_across_runs() {
+        let dir = TempDir::new("stable");
+
+        for (len, run_size) in [(0, 4), (1, 4), (4, 4), (5, 4), (1000, 7), (1000, 1)] {
+            let elements = random_pairs(len);
+            let mut expected = elements.clone();
+
+            expected.sort_by(by_key);
+
+            let sorted =
+                LinkedList::external_sort_by(elements, by_key, run_size, &dir.0, PairCodec)
+                    .expect("sorting should succeed");
+
+            assert!(
+                sorted.iter().eq(expected.iter()),
+                "len {len}, run size {run_size}"
+            );
+            assert_eq!(sorted.check_invariants(), Ok(()));
+            assert_eq!(dir.file_count(), 0);
+        }
+    }
+
+    #[test]
+    fn spills_only_when_the_source_exceeds_one_run() {
+        let dir = TempDir::new("spill");
+
+        let iter =
+            LinkedList::external_sort_iter_by(random_pairs(10), by_key, 10, &dir.0, PairCodec)
+                .expect("sorting should succeed");
+
+        assert_eq!(dir.file_count(), 0);
+        assert_eq!(iter.count(), 10);
+
+        let mut iter =
+            LinkedList::external_sort_iter_by(random_pairs(25), by_key, 10, &dir.0, PairCodec)
+                .expect("sorting should succeed");
+
+        assert_eq!(dir.file_count(), 3);
+        assert!(iter.next().is_some_and(|element| element.is_ok()));
+
+        drop(iter);
+
+        assert_eq!(dir.file_count(), 0);
+    }
+
+    #[test]
+    fn reports_decode_errors_once() {
+        let dir = TempDir::new("decode");
+        let broken = |limit| BrokenCodec {
+            limit,
+            decoded: Cell::new(0),
+        };
+
+        match LinkedList::external_sort_iter_by(random_pairs(20), by_key, 5, &dir.0, broken(2)) {
+            Err(error) => assert_eq!(error.kind(), io::ErrorKind::InvalidData),
+            Ok(_) => panic!("reading the first element of each run should fail"),
+        }
+
+        assert_eq!(dir.file_count(), 0);
+
+        let mut iter =
+            LinkedList::external_sort_iter_by(random_pairs(20), by_key, 5, &dir.0, broken(4))
+                .expect("the first element of each run should be readable");
+
+        assert!(iter.next().is_some_and(|element| element.is_ok()));
+        assert!(iter.next().is_some_and(|element| element.is_err()));
+        assert!(iter.next().is_none());
+        assert_eq!(dir.file_count(), 0);
+    }
+
+    #[test]
+    fn reports_missing_temp_dir() {
+        let dir = TempDir::new("missing");
+        let missing = dir.0.join("missing");
+
+        assert!(
+            LinkedList::external_sort_by(random_pairs(20), by_key, 5, &missing, PairCodec).is_err()
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "run size must be positive")]
+    fn rejects_empty_runs() {
+        let _ = LinkedList::external_sort_by(
+            random_pairs(3),
+            by_key,
+            0,
+            &std::env::temp_dir(),
+            PairCodec,
+        );
+    }
+}
+
+mod file_list {
+    use std::{
+        cell::Cell,
+        fs::{self, File, OpenOptions},
+        io::{self, Seek, SeekFrom, Write},
+        path::Path,
+    };
+
+    use super::{FileList, FileListError, FileStorage, InvariantViolation, utils::TempDir};
+
+    /// A file whose writes fail once `writes_left` runs out.
+    struct FailingFile {
+        file: File,
+        writes_left: Cell<usize>,
+    }
+
+    impl FileStorage for FailingFile {
+        fn read_at(&self, offset: u64, buffer: &mut [u8]) -> io::Result<()> {
+            self.file.read_at(offset, buffer)
+        }
+
+        fn write_at(&self, offset: u64, buffer: &[u8]) -> io::Result<()> {
+            let Some(writes_left) = self.writes_left.get().checked_sub(1) else {
+                return Err(io::Error::other("no writes left"));
+            };
+
+            self.writes_left.set(writes_left);
+            self.file.write_at(offset, buffer)
+        }
+
+        fn size(&self) -> io::Result<u64> {
+            self.file.size()
+        }
+
+        fn set_size(&self, size: u64) -> io::Result<()> {
+            self.file.set_size(size)
+        }
+
+        fn sync(&self) -> io::Result<()> {
+            self.file.sync()
+        }
+    }
+
+    /// Offset of the slot at `index` for `u64` elements: a 48-byte header and
+    /// a 91-byte edit log, then slots of a state byte, two links and the
+    /// element.
+    const fn slot(index: u64) -> u64 {
+        139 + index * 25
+    }
+
+    /// Logs an edit of the slots at `offsets` like `FileList` does before
+    /// making it: the slot count and header words, then each offset with the
+    /// 17 bytes stored there, then the pending state byte.
+    fn log_edit(path: &Path, offsets: &[u64]) {
+        let bytes = fs::read(path).expect("list file should be readable");
+        let slot_count = (bytes.len() as u64 - slot(0)) / 25;
+        let mut log = [0; 91];
+
+        log[..8].copy_from_slice(&slot_count.to_le_bytes());
+        log[8..40].copy_from_slice(&bytes[16..48]);
+
+        for (index, &offset) in offsets.iter().enumerate() {
+            let entry = &mut log[40 + index * 25..65 + index * 25];
+            let start = usize::try_from(offset).expect("offsets fit in usize");
+
+            entry[..8].copy_from_slice(&offset.to_le_bytes());
+            entry[8..].copy_from_slice(&bytes[start..start + 17]);
+        }
+
+        log[90] = 1;
+        overwrite(path, 48, &log);
+    }
+
+    fn elements<S: FileStorage>(list: &FileList<u64, S>) -> Vec<u64> {
+        list.iter()
+            .collect::<Result<_, _>>()
+            .expect("elements should be readable")
+    }
+
+    fn overwrite(path: &Path, offset: u64, bytes: &[u8]) {
+        let mut file = OpenOptions::new()
+            .write(true)
+            .open(path)
+            .expect("list file should exist");
+
+        file.seek(SeekFrom::Start(offset))
+            .expect("offset should be seekable");
+        file.write_all(bytes).expect("file should be writable");
+    }
+
+    fn committed_list(path: &Path, elements: &[u64]) -> FileList<u64> {
+        let mut list = FileList::create(path).expect("list file should be creatable");
+
+        for element in elements {
+            list.push_back(element).expect("push should succeed");
+        }
+
+        list.commit().expect("commit should succeed");
+
+        list
+    }
+
+    #[test]
+    fn pushes_and_pops_at_both_ends() {
+        let dir = TempDir::new("file-list-ends");
+        let mut list = FileList::create(dir.0.join("list")).expect("list file should be creatable");
+
+        assert_eq!(list.pop_front().ok(), Some(None));
+        assert_eq!(list.back().ok(), Some(None));
+
+        list.push_back(&2).expect("push should succeed");
+        list.push_front(&1).expect("push should succeed");
+        list.push_back(&3).expect("push should succeed");
+
+        assert_eq!(elements(&list), [1, 2, 3]);
+        assert_eq!(list.front().ok(), Some(Some(1)));
+        assert_eq!(list.back().ok(), Some(Some(3)));
+        assert_eq!(list.pop_back().ok(), Some(Some(3)));
+        assert_eq!(list.pop_front().ok(), Some(Some(1)));
+        assert_eq!(list.pop_front().ok(), Some(Some(2)));
+        assert_eq!(list.pop_back().ok(), Some(None));
+        assert!(list.is_empty());
+        assert!(list.validate().is_ok());
+    }
+
+    #[test]
+    fn iterates_in_both_directions() {
+        let dir = TempDir::new("file-list-iter");
+        let list = committed_list(&dir.0.join("list"), &[1, 2, 3, 4]);
+        let backwards: Vec<u64> = list
+            .iter()
+            .rev()
+            .collect::<Result<_, _>>()
+            .expect("elements should be readable");
+        let mut iter = list.iter();
+
+        assert_eq!(backwards, [4, 3, 2, 1]);
+        assert_eq!(iter.next().and_then(Result::ok), Some(1));
+        assert_eq!(iter.next_back().and_then(Result::ok), Some(4));
+        assert_eq!(iter.size_hint(), (2, Some(2)));
+        assert_eq!(iter.next_back().and_then(Result::ok), Some(3));
+        assert_eq!(iter.next().and_then(Result::ok), Some(2));
+        assert!(iter.next().is_none());
+        assert!(iter.next_back().is_none());
+    }
+
+    #[test]
+    fn survives_reopening() {
+        let dir = TempDir::new("file-list-reopen");
+        let path = dir.0.join("list");
+        let mut list = committed_list(&path, &[1, 2, 3]);
+
+        list.pop_front().expect("pop should succeed");
+        list.push_back(&4).expect("push should succeed");
+        list.commit().expect("commit should succeed");
+        drop(list);
+
+        let list = FileList::<u64>::open(&path).expect("list file should be valid");
+
+        assert_eq!(elements(&list), [2, 3, 4]);
+        assert_eq!(list.len(), 3);
+    }
+
+    #[test]
+    fn reuses_freed_slots() {
+        let dir = TempDir::new("file-list-free");
+        let path = dir.0.join("list");
+        let mut list = committed_list(&path, &[1, 2, 3, 4]);
+        let file_len = || fs::metadata(&path).expect("list file should exist").len();
+        let full_len = file_len();
+
+        list.pop_back().expect("pop should succeed");
+        list.pop_front().expect("pop should succeed");
+        list.commit().expect("commit should succeed");
+        drop(list);
+
+        let mut list = FileList::<u64>::open(&path).expect("free slots should validate");
+
+        list.push_front(&5).expect("push should succeed");
+        list.push_front(&6).expect("push should succeed");
+
+        assert_eq!(file_len(), full_len);
+        assert_eq!(elements(&list), [6, 5, 2, 3]);
+
+        list.push_back(&7).expect("push should succeed");
+
+        assert_eq!(file_len(), full_len + 25);
+        assert!(list.validate().is_ok());
+    }
+
+    #[test]
+    fn rejects_foreign_files() {
+        let dir = TempDir::new("file-list-foreign");
+        let path = dir.0.join("list");
+
+        fs::write(&path, b"not a list").expect("file should be writable");
+
+        assert!(matches!(
+            FileList::<u64>::open(&path),
+            Err(FileListError::InvalidHeader)
+        ));
+
+        drop(committed_list(&path, &[1]));
+        overwrite(&path, 4, &[9]);
+
+        assert!(matches!(
+            FileList::<u64>::open(&path),
+            Err(FileListError::UnsupportedVersion(9))
+        ));
+
+        drop(committed_list(&path, &[1]));
+
+        assert!(matches!(
+            FileList::<u32>::open(&path),
+            Err(FileListError::ElementSizeMismatch {
+                expected: 4,
+                found: 8
+            })
+        ));
+    }
+
+    #[test]
+    fn detects_broken_links() {
+        let dir = TempDir::new("file-list-links");
+        let path = dir.0.join("list");
+
+        drop(committed_list(&path, &[1, 2, 3]));
+        overwrite(&path, slot(2) + 1, &slot(0).to_le_bytes());
+
+        assert!(matches!(
+            FileList::<u64>::open(&path),
+            Err(FileListError::Invariant(
+                InvariantViolation::BrokenBackLink { index: 2 }
+            ))
+        ));
+
+        drop(committed_list(&path, &[1, 2, 3]));
+        overwrite(&path, slot(2) + 9, &slot(0).to_le_bytes());
+
+        assert!(matches!(
+            FileList::<u64>::open(&path),
+            Err(FileListError::Invariant(InvariantViolation::TailHasNext))
+        ));
+
+        drop(committed_list(&path, &[1, 2, 3]));
+        overwrite(&path, slot(1) + 9, &7_u64.to_le_bytes());
+
+        assert!(matches!(
+            FileList::<u64>::open(&path),
+            Err(FileListError::InvalidOffset { offset: 7 })
+        ));
+    }
+
+    #[test]
+    fn recovers_interrupted_edits() {
+        let dir = TempDir::new("file-list-recover");
+        let path = dir.0.join("list");
+
+        drop(committed_list(&path, &[1, 2, 3]));
+        // A push_back that appended part of its slot and linked the old tail
+        // to it, but never updated the header.
+        log_edit(&path, &[slot(2)]);
+        overwrite(&path, slot(2) + 9, &slot(3).to_le_bytes());
+        overwrite(&path, slot(3), &[1; 10]);
+
+        let mut list = FileList::<u64>::open(&path).expect("the push should be undone");
+
+        assert_eq!(elements(&list), [1, 2, 3]);
+        assert_eq!(
+            fs::metadata(&path).expect("list file should exist").len(),
+            slot(3)
+        );
+
+        // A pop_front that unlinked and freed the head, but never updated the
+        // header.
+        list.pop_back().expect("pop should succeed");
+        list.commit().expect("commit should succeed");
+        drop(list);
+        log_edit(&path, &[slot(0), slot(1)]);
+        overwrite(&path, slot(1) + 1, &0_u64.to_le_bytes());
+        overwrite(&path, slot(0), &[2]);
+        overwrite(&path, slot(0) + 9, &slot(2).to_le_bytes());
+
+        let list = FileList::<u64>::open(&path).expect("the pop should be undone");
+
+        assert_eq!(elements(&list), [1, 2]);
+        assert!(list.validate().is_ok());
+        drop(list);
+
+        let mut list = FileList::<u64>::open(&path).expect("the log should be cleared");
+
+        list.push_back(&3)
+            .expect("push should reuse the freed slot");
+
+        assert_eq!(elements(&list), [1, 2, 3]);
+        assert!(list.validate().is_ok());
+    }
+
+    #[test]
+    fn failed_edits_poison_the_list() {
+        type Edit = fn(&mut FileList<u64, FailingFile>) -> Result<(), FileListError>;
+
+        let dir = TempDir::new("file-list-failing");
+        let path = dir.0.join("list");
+        let edits: [(Edit, &[u64]); 4] = [
+            (|list| list.push_front(&0), &[0, 1, 2, 3]),
+            (|list| list.push_back(&4), &[1, 2, 3, 4]),
+            (|list| list.pop_front().map(drop), &[2, 3]),
+            (|list| list.pop_back().map(drop), &[1, 2]),
+        ];
+
+        for (edit, edited) in edits {
+            for writes in 0.. {
+                assert!(writes < 10, "the edit should succeed with enough writes");
+                drop(committed_list(&path, &[1, 2, 3]));
+
+                let file = OpenOptions::new()
+                    .read(true)
+                    .write(true)
+                    .open(&path)
+                    .expect("list file should exist");
+                let storage = FailingFile {
+                    file,
+                    writes_left: Cell::new(writes),
+                };
+                let mut list = FileList::open_in(storage).expect("list should be valid");
+
+                if edit(&mut list).is_ok() {
+                    assert_eq!(elements(&list), edited);
+                    break;
+                }
+
+                assert_eq!(list.len(), 3);
+                assert!(matches!(edit(&mut list), Err(FileListError::Poisoned)));
+                assert!(matches!(list.commit(), Err(FileListError::Poisoned)));
+                drop(list);
+
+                let list = FileList::<u64>::open(&path).expect("the failed edit should be undone");
+
+                assert_eq!(elements(&list), [1, 2, 3], "{writes} writes");
+            }
+        }
+    }
+
+    #[test]
+    fn detects_unlogged_edits() {
+        let dir = TempDir::new("file-list-crash");
+        let path = dir.0.join("list");
+
+        drop(committed_list(&path, &[1, 2, 3]));
+        // A pop that freed its slot without logging it or updating the header.
+        overwrite(&path, slot(0), &[2]);
+
+        assert!(matches!(
+            FileList::<u64>::open(&path),
+            Err(FileListError::InvalidSlotState { offset }) if offset == slot(0)
+        ));
+
+        drop(committed_list(&path, &[1, 2, 3]));
+        // A push that wrote its slot without logging it or linking it in.
+        overwrite(&path, slot(3), &[1; 25]);
+
+        assert!(matches!(
+            FileList::<u64>::open(&path),
+            Err(FileListError::LeakedSlots {
+                expected: 4,
+                found: 3
+            })
+        ));
+
+        drop(committed_list(&path, &[1, 2, 3]));
+        // An unlogged write cut off in the middle of a slot.
+        overwrite(&path, slot(3), &[1; 10]);
+
+        assert!(matches!(
+            FileList::<u64>::open(&path),
+            Err(FileListError::InvalidOffset { .. })
+        ));
+    }
+}
+
+mod binary {
+    use std::io::{self, Read};
+
+    use super::{Decode, DecodeError, Encode, LinkedList, utils::list_of};
+
+    fn encoded<T: Encode>(list: &LinkedList<T>) -> Vec<u8> {
+        let mut bytes = vec![];
+
+        list.write_to(&mut bytes)
+            .expect("writing to a Vec cannot fail");
+
+        bytes
+    }
+
+    /// Hands out one byte per read, like a slow stream.
+    struct Trickle<'a>(&'a [u8]);
+
+    impl Read for Trickle<'_> {
+        fn read(&mut self, buffer: &mut [u8]) -> io::Result<usize> {
+            let Some((first, rest)) = self.0.split_first() else {
+                return Ok(0);
+            };
+
+            if let Some(slot) = buffer.first_mut() {
+                *slot = *first;
+                self.0 = rest;
+
+                return Ok(1);
+            }
+
+            Ok(0)
+        }
+    }
+
+    #[test]
+    fn writes_header_and_little_endian_elements() {
+        let bytes = encoded(&list_of(vec![1_u16, 0x0203]));
+
+        assert_eq!(
+            bytes,
+            [
+                b'L', b'L', b'S', b'T', 1, 2, 0, 0, 0, 0, 0, 0, 0, 1, 0, 3, 2
+            ]
+        );
+    }
+
+    #[test]
+    fn round_trips_every_supported_type() {
+        let list = list_of(vec![
+            (-5_i64, 2.5_f64, true, Some(String::from("naïve"))),
+            (i64::MIN, f64::INFINITY, false, None),
+        ]);
+        let read: LinkedList<(i64, f64, bool, Option<String>)> =
+            LinkedList::read_from(&mut encoded(&list).as_slice()).expect("list should decode");
+
+        assert!(read.iter().eq(list.iter()));
+
+        let list = list_of(vec![
+            (vec![0_u8, 255], usize::MAX, -1_isize),
+            (vec![], 0, 7),
+        ]);
+        let read: LinkedList<(Vec<u8>, usize, isize)> =
+            LinkedList::read_from(&mut encoded(&list).as_slice()).expect("list should decode");
+
+        assert!(read.iter().eq(list.iter()));
+
+        let list = list_of(vec![(1_u8,), (u8::MAX,)]);
+        let read: LinkedList<(u8,)> =
+            LinkedList::read_from(&mut encoded(&list).as_slice()).expect("list should decode");
+
+        assert!(read.iter().eq(list.iter()));
+        assert_eq!(read.check_invariants(), Ok(()));
+    }
+
+    #[test]
+    fn streams_without_reading_ahead() {
+        let mut bytes = encoded(&list_of(vec![String::from("a"), String::from("bc")]));
+
+        bytes.extend(encoded(&list_of(vec![String::from("d")])));
+
+        let mut reader = Trickle(&bytes);
+        let first: LinkedList<String> =
+            LinkedList::read_from(&mut reader).expect("list should decode");
+        let second: LinkedList<String> =
+            LinkedList::read_from(&mut reader).expect("list should decode");
+
+        assert!(first.iter().eq(["a", "bc"]));
+        assert!(second.iter().eq(["d"]));
+        assert!(reader.0.is_empty());
+    }
+
+    #[test]
+    fn rejects_malformed_input() {
+        let read = |bytes: &[u8]| LinkedList::<Option<String>>::read_from(&mut &bytes[..]);
+        let valid = encoded(&list_of(vec![Some(String::from("ok"))]));
+
+        assert!(matches!(read(b"LIST\x01"), Err(DecodeError::InvalidMagic)));
+        assert!(matches!(
+            read(b"LLST\x02"),
+            Err(DecodeError::UnsupportedVersion(2))
+        ));
+
+        let mut bad_tag = valid.clone();
+
+        bad_tag[13] = 2;
+
+        assert!(matches!(read(&bad_tag), Err(DecodeError::InvalidTag(2))));
+
+        let mut bad_utf8 = valid.clone();
+
+        bad_utf8[22] = 0xff;
+
+        assert!(matches!(read(&bad_utf8), Err(DecodeError::InvalidUtf8)));
+
+        let truncated = &valid[..valid.len() - 1];
+
+        assert!(matches!(
+            read(truncated),
+            Err(DecodeError::Io(error)) if error.kind() == io::ErrorKind::UnexpectedEof
+        ));
+
+        let mut huge_len = valid;
+
+        huge_len[14..22].copy_from_slice(&u64::MAX.to_le_bytes());
+
+        assert!(matches!(read(&huge_len), Err(DecodeError::Io(_))));
+    }
+    #[test]
+    fn reports_integer_overflow_apart_from_lengths() {
+        let bytes = i64::MIN.to_le_bytes();
+        let decoded = <isize as Decode>::decode(&mut &bytes[..]);
+
+        if cfg!(target_pointer_width = "64") {
+            assert_eq!(decoded.ok(), Some(isize::MIN));
+        } else {
+            assert!(matches!(decoded, Err(DecodeError::IntegerOverflow)));
+        }
+
+        assert_eq!(
+            DecodeError::IntegerOverflow.to_string(),
+            "integer does not fit in usize or isize"
+        );
+    }
+}
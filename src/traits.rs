@@ -1,8 +1,39 @@
-use std::fmt::{self, Display};
+use std::{
+    cmp,
+    fmt::{self, Debug, Display},
+    fs::{self, File},
+    io::{self, Read, Seek, SeekFrom, Write},
+    mem,
+    ops::Deref,
+    ptr::{self, NonNull},
+    rc::Rc,
+    str::FromStr,
+    sync::{Arc, atomic::Ordering},
+};
 
 use super::{
-    LinkedList,
+    LinkedList, UnrolledLinkedList, XorLinkedList,
+    binary::{Decode, DecodeError, Encode, decode_bytes, decode_tag, encode_len},
+    channel::{IntoIter, Iter, Receiver, Sender, TryIter},
+    concurrent_queue::{ConcurrentQueue, QueueConsumer},
+    cow_list::CowList,
+    diagram::DiagramOptions,
+    display::{DisplayOptions, DisplayWith},
+    external_sort::{ExternalSortIter, RunCodec, RunFile},
+    file_list::{FileIter, FileList, FileListError, FileStorage, FixedCodec},
+    journal::{
+        Journal, ParseJournalError, RecordedList, RetainOutcome, parse_journal, write_entry,
+    },
     list::{ListIntoIter, ListIter, ListIterMut},
+    locking_list::LockingList,
+    node::LockingNode,
+    node_allocator::deallocate_node,
+    parse::{ParseListError, parse_list, write_escaped},
+    persistent_list::{PersistentIter, PersistentList},
+    sort::Chains,
+    transaction::Transaction,
+    unrolled_list::{UnrolledIntoIter, UnrolledIter, UnrolledIterMut},
+    xor_list::{XorIntoIter, XorIter, XorIterMut},
 };
 
 impl<'a, T> Iterator for ListIter<'a, T> {
@@ -36,6 +67,7 @@ impl<'a, T> Iterator for ListIterMut<'a, T> {
         }
     }
 }
+
 impl<T> Iterator for ListIntoIter<T> {
     type Item = T;
 
@@ -84,10 +116,10 @@ impl<T: Display> Display for LinkedList<T> {
         while let Some(node) = current_node {
             let node_ref = unsafe { node.as_ref() };
 
+            write_escaped(f, &node_ref.element)?;
+
             if node_ref.next.is_some() {
-                write!(f, "{} <-> ", node_ref.element)?;
-            } else {
-                write!(f, "{}", node_ref.element)?;
+                write!(f, " <-> ")?;
             }
 
             current_node = node_ref.next;
@@ -97,9 +129,180 @@ impl<T: Display> Display for LinkedList<T> {
     }
 }
 
+impl Default for DiagramOptions {
+    fn default() -> Self {
+        Self { width: 80 }
+    }
+}
+
+impl Default for DisplayOptions<'_> {
+    fn default() -> Self {
+        Self {
+            open: "[",
+            separator: " <-> ",
+            close: "]",
+            max_items: None,
+            reverse: false,
+        }
+    }
+}
+
+impl<T: Display> Display for DisplayWith<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let options = &self.options;
+        let len = self.list.len();
+        let shown = options
+            .max_items
+            .map_or(len, |max_items| max_items.min(len));
+        let mut current_node = if options.reverse {
+            self.list.tail
+        } else {
+            self.list.head
+        };
+
+        f.write_str(options.open)?;
+
+        if f.alternate() {
+            f.write_str("\n")?;
+        }
+
+        for position in 0..shown {
+            let Some(node) = current_node else {
+                break;
+            };
+
+            let node_ref = unsafe { node.as_ref() };
+            let index = if options.reverse {
+                len - 1 - position
+            } else {
+                position
+            };
+
+            if f.alternate() {
+                write!(f, "    {index}: ")?;
+                Display::fmt(&node_ref.element, f)?;
+                f.write_str("\n")?;
+            } else {
+                if position > 0 {
+                    f.write_str(options.separator)?;
+                }
+
+                Display::fmt(&node_ref.element, f)?;
+            }
+
+            current_node = if options.reverse {
+                node_ref.previous
+            } else {
+                node_ref.next
+            };
+        }
+
+        if shown < len {
+            if f.alternate() {
+                f.write_str("    ...\n")?;
+            } else {
+                if shown > 0 {
+                    f.write_str(options.separator)?;
+                }
+
+                f.write_str("...")?;
+            }
+        }
+
+        f.write_str(options.close)
+    }
+}
+
+impl<T: FromStr> FromStr for LinkedList<T> {
+    type Err = ParseListError<T::Err>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_list(s)
+    }
+}
+
+impl<T: Display> Display for Journal<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for entry in &self.entries {
+            write_entry(f, entry)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<T: FromStr> FromStr for Journal<T> {
+    type Err = ParseJournalError<T::Err>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_journal(s)
+    }
+}
+
+impl<T> Default for Journal<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Default for RecordedList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl<T> Drop for LinkedList<T> {
     fn drop(&mut self) {
-        while self.pop_front().is_some() {}
+        self.clear();
+    }
+}
+
+impl<'a, T> IntoIterator for &'a Transaction<'_, T> {
+    type Item = &'a T;
+    type IntoIter = ListIter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<T> Drop for Transaction<'_, T> {
+    fn drop(&mut self) {
+        if self.committed {
+            self.release_removed();
+        } else {
+            self.rollback();
+        }
+    }
+}
+
+impl<T, F, C> Iterator for ExternalSortIter<T, F, C>
+where
+    F: FnMut(&T, &T) -> cmp::Ordering,
+    C: RunCodec<T>,
+{
+    type Item = io::Result<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_merged()
+    }
+}
+
+impl Drop for RunFile {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.path);
+    }
+}
+
+impl<T> Drop for Chains<'_, T> {
+    fn drop(&mut self) {
+        self.relink();
+    }
+}
+
+impl<T> Drop for RetainOutcome<'_, T> {
+    fn drop(&mut self) {
+        self.finish();
     }
 }
 
@@ -108,3 +311,798 @@ impl<T> Default for LinkedList<T> {
         Self::new()
     }
 }
+
+impl<T: Clone> Clone for LinkedList<T> {
+    fn clone(&self) -> Self {
+        let mut list = Self::new();
+
+        for element in self {
+            list.push_back(element.clone());
+        }
+
+        list
+    }
+}
+
+// The list owns its nodes exclusively, so it is as thread-safe as its
+// elements, like `std::collections::LinkedList`.
+unsafe impl<T: Send> Send for LinkedList<T> {}
+
+unsafe impl<T: Sync> Sync for LinkedList<T> {}
+
+unsafe impl<T: Sync> Send for ListIter<'_, T> {}
+
+unsafe impl<T: Sync> Sync for ListIter<'_, T> {}
+
+unsafe impl<T: Send> Send for ListIterMut<'_, T> {}
+
+unsafe impl<T: Sync> Sync for ListIterMut<'_, T> {}
+
+impl<'a, T, const N: usize> Iterator for UnrolledIter<'a, T, N> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.current?;
+
+        unsafe {
+            let node_ref = node.as_ref();
+            let element = &*node_ref.as_ptr().add(self.offset);
+
+            self.offset += 1;
+
+            if self.offset == node_ref.len {
+                self.current = node_ref.next;
+                self.offset = 0;
+            }
+
+            Some(element)
+        }
+    }
+}
+
+impl<'a, T, const N: usize> Iterator for UnrolledIterMut<'a, T, N> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.current?.as_ptr();
+
+        // Elements of the current node may already be borrowed mutably, so the
+        // node is only accessed through raw places to avoid aliasing them.
+        unsafe {
+            let elements = (&raw mut (*node).elements).cast::<T>();
+            let element = &mut *elements.add(self.offset);
+
+            self.offset += 1;
+
+            if self.offset == (*node).len {
+                self.current = (*node).next;
+                self.offset = 0;
+            }
+
+            Some(element)
+        }
+    }
+}
+
+impl<T, const N: usize> Iterator for UnrolledIntoIter<T, N> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.list.pop_front()
+    }
+}
+
+impl<T, const N: usize> IntoIterator for UnrolledLinkedList<T, N> {
+    type Item = T;
+    type IntoIter = UnrolledIntoIter<T, N>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        UnrolledIntoIter { list: self }
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a UnrolledLinkedList<T, N> {
+    type Item = &'a T;
+    type IntoIter = UnrolledIter<'a, T, N>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a mut UnrolledLinkedList<T, N> {
+    type Item = &'a mut T;
+    type IntoIter = UnrolledIterMut<'a, T, N>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<T, const N: usize> Drop for UnrolledLinkedList<T, N> {
+    fn drop(&mut self) {
+        while self.pop_front().is_some() {}
+    }
+}
+
+impl<T, const N: usize> Default for UnrolledLinkedList<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, T> Iterator for XorIter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        let node = self.front?;
+
+        unsafe {
+            let node_ref = node.as_ref();
+
+            self.front = node_ref.other(self.front_previous);
+            self.front_previous = Some(node);
+            self.remaining -= 1;
+
+            Some(&node_ref.element)
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T> DoubleEndedIterator for XorIter<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        let node = self.back?;
+
+        unsafe {
+            let node_ref = node.as_ref();
+
+            self.back = node_ref.other(self.back_next);
+            self.back_next = Some(node);
+            self.remaining -= 1;
+
+            Some(&node_ref.element)
+        }
+    }
+}
+
+impl<'a, T> Iterator for XorIterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        let mut node = self.front?;
+
+        unsafe {
+            let node_ref = node.as_mut();
+
+            self.front = node_ref.other(self.front_previous);
+            self.front_previous = Some(node);
+            self.remaining -= 1;
+
+            Some(&mut node_ref.element)
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T> DoubleEndedIterator for XorIterMut<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        let mut node = self.back?;
+
+        unsafe {
+            let node_ref = node.as_mut();
+
+            self.back = node_ref.other(self.back_next);
+            self.back_next = Some(node);
+            self.remaining -= 1;
+
+            Some(&mut node_ref.element)
+        }
+    }
+}
+
+impl<T> Iterator for XorIntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.list.pop_front()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.list.len(), Some(self.list.len()))
+    }
+}
+
+impl<T> DoubleEndedIterator for XorIntoIter<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.list.pop_back()
+    }
+}
+
+impl<T> IntoIterator for XorLinkedList<T> {
+    type Item = T;
+    type IntoIter = XorIntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        XorIntoIter { list: self }
+    }
+}
+
+impl<'a, T> IntoIterator for &'a XorLinkedList<T> {
+    type Item = &'a T;
+    type IntoIter = XorIter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut XorLinkedList<T> {
+    type Item = &'a mut T;
+    type IntoIter = XorIterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<T: Display> Display for XorLinkedList<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[")?;
+
+        for (index, element) in self.iter().enumerate() {
+            if index > 0 {
+                write!(f, " <-> ")?;
+            }
+
+            write_escaped(f, element)?;
+        }
+
+        write!(f, "]")
+    }
+}
+
+impl<T> Drop for XorLinkedList<T> {
+    fn drop(&mut self) {
+        while self.pop_front().is_some() {}
+    }
+}
+
+impl<T> Default for XorLinkedList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, T> Iterator for PersistentIter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.current?;
+
+        self.current = node.next.as_deref();
+        self.remaining -= 1;
+
+        Some(&node.element)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'a, T> IntoIterator for &'a PersistentList<T> {
+    type Item = &'a T;
+    type IntoIter = PersistentIter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<T> Clone for PersistentList<T> {
+    fn clone(&self) -> Self {
+        Self {
+            head: self.head.clone(),
+            size: self.size,
+        }
+    }
+}
+
+impl<T: Debug> Debug for PersistentList<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T> From<LinkedList<T>> for PersistentList<T> {
+    fn from(mut list: LinkedList<T>) -> Self {
+        let mut persistent = Self::new();
+
+        while let Some(element) = list.pop_back() {
+            persistent = persistent.cons(element);
+        }
+
+        persistent
+    }
+}
+
+impl<T: Clone> From<&PersistentList<T>> for LinkedList<T> {
+    fn from(persistent: &PersistentList<T>) -> Self {
+        let mut list = Self::new();
+
+        for element in persistent {
+            list.push_back(element.clone());
+        }
+
+        list
+    }
+}
+
+impl<T> Drop for PersistentList<T> {
+    fn drop(&mut self) {
+        // Unlinks uniquely owned nodes one at a time, since the recursive drop
+        // of a long chain would overflow the stack. The first shared node
+        // belongs to another version and ends the walk.
+        let mut current_node = self.head.take();
+
+        while let Some(node) = current_node {
+            current_node = match Rc::try_unwrap(node) {
+                Ok(mut node) => node.next.take(),
+                Err(_) => break,
+            };
+        }
+    }
+}
+
+impl<T> Default for PersistentList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Clone for CowList<T> {
+    fn clone(&self) -> Self {
+        Self {
+            list: Arc::clone(&self.list),
+        }
+    }
+}
+
+impl<T> Deref for CowList<T> {
+    type Target = LinkedList<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.list
+    }
+}
+
+impl<'a, T: Clone> IntoIterator for &'a mut CowList<T> {
+    type Item = &'a mut T;
+    type IntoIter = ListIterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<T> From<LinkedList<T>> for CowList<T> {
+    fn from(list: LinkedList<T>) -> Self {
+        Self {
+            list: Arc::new(list),
+        }
+    }
+}
+
+impl<T: Display> Display for CowList<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Display::fmt(&*self.list, f)
+    }
+}
+
+impl<T: Debug> Debug for CowList<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.list.iter()).finish()
+    }
+}
+
+impl<T> Default for CowList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Drop for ConcurrentQueue<T> {
+    fn drop(&mut self) {
+        let mut elements = vec![];
+
+        while let Some(element) = unsafe { self.pop_unchecked() } {
+            elements.push(element);
+        }
+
+        unsafe { self.deallocate_stub() };
+
+        // Dropped after the nodes are freed, so a panicking destructor cannot
+        // leak them.
+        drop(elements);
+    }
+}
+
+impl<T> Drop for QueueConsumer<'_, T> {
+    fn drop(&mut self) {
+        self.queue.consumer_taken.store(false, Ordering::Release);
+    }
+}
+
+impl<T> Default for ConcurrentQueue<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// Elements move between threads through the queue, and `tail` is only ever
+// touched by the single consumer.
+unsafe impl<T: Send> Send for ConcurrentQueue<T> {}
+
+unsafe impl<T: Send> Sync for ConcurrentQueue<T> {}
+
+impl<T> Drop for LockingList<T> {
+    fn drop(&mut self) {
+        let mut elements = Vec::with_capacity(*self.size.get_mut());
+        let mut current_node = NonNull::new(*self.head.get_mut());
+
+        while let Some(node) = current_node {
+            let LockingNode { next, element, .. } = unsafe { ptr::read(node.as_ptr()) };
+
+            unsafe { deallocate_node(node) };
+
+            current_node = NonNull::new(next.get());
+            elements.push(element);
+        }
+
+        drop(elements);
+    }
+}
+
+impl<T> Default for LockingList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// Elements are moved out by `remove` and shared with every thread that
+// compares against them.
+unsafe impl<T: Send> Send for LockingList<T> {}
+
+unsafe impl<T: Send + Sync> Sync for LockingList<T> {}
+
+impl<T> Clone for Sender<T> {
+    fn clone(&self) -> Self {
+        self.shared.lock().senders += 1;
+
+        Self {
+            shared: Arc::clone(&self.shared),
+        }
+    }
+}
+
+impl<T> Drop for Sender<T> {
+    fn drop(&mut self) {
+        let mut state = self.shared.lock();
+
+        state.senders -= 1;
+
+        if state.senders == 0 {
+            drop(state);
+
+            // Wakes the receiver so that it can observe the disconnection.
+            self.shared.not_empty.notify_all();
+        }
+    }
+}
+
+impl<T> Drop for Receiver<T> {
+    fn drop(&mut self) {
+        let mut state = self.shared.lock();
+
+        state.receiver_alive = false;
+
+        // Queued elements can no longer be received, so they are dropped now
+        // and outside the lock.
+        let queue = mem::take(&mut state.queue);
+
+        drop(state);
+
+        self.shared.not_full.notify_all();
+
+        drop(queue);
+    }
+}
+
+impl<T> Iterator for Iter<'_, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.receiver.recv().ok()
+    }
+}
+
+impl<T> Iterator for TryIter<'_, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.receiver.try_recv().ok()
+    }
+}
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.receiver.recv().ok()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a Receiver<T> {
+    type Item = T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<T> IntoIterator for Receiver<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter { receiver: self }
+    }
+}
+
+impl<T: FixedCodec, S: FileStorage> Iterator for FileIter<'_, T, S> {
+    type Item = Result<T, FileListError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.read_front()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = usize::try_from(self.remaining).unwrap_or(usize::MAX);
+
+        (remaining, Some(remaining))
+    }
+}
+
+impl<T: FixedCodec, S: FileStorage> DoubleEndedIterator for FileIter<'_, T, S> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.read_back()
+    }
+}
+
+impl<'a, T: FixedCodec, S: FileStorage> IntoIterator for &'a FileList<T, S> {
+    type Item = Result<T, FileListError>;
+    type IntoIter = FileIter<'a, T, S>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl FileStorage for File {
+    fn read_at(&self, offset: u64, buffer: &mut [u8]) -> io::Result<()> {
+        let mut file = self;
+
+        file.seek(SeekFrom::Start(offset))?;
+        file.read_exact(buffer)
+    }
+
+    fn write_at(&self, offset: u64, buffer: &[u8]) -> io::Result<()> {
+        let mut file = self;
+
+        file.seek(SeekFrom::Start(offset))?;
+        file.write_all(buffer)
+    }
+
+    fn size(&self) -> io::Result<u64> {
+        Ok(self.metadata()?.len())
+    }
+
+    fn set_size(&self, size: u64) -> io::Result<()> {
+        self.set_len(size)
+    }
+
+    fn sync(&self) -> io::Result<()> {
+        self.sync_data()
+    }
+}
+
+impl From<io::Error> for FileListError {
+    fn from(error: io::Error) -> Self {
+        Self::Io(error)
+    }
+}
+
+macro_rules! impl_fixed_codec {
+    ($($int:ty),*) => {
+        $(
+            impl FixedCodec for $int {
+                const SIZE: usize = size_of::<Self>();
+
+                fn encode(&self, bytes: &mut [u8]) {
+                    bytes.copy_from_slice(&self.to_le_bytes());
+                }
+
+                fn decode(bytes: &[u8]) -> Option<Self> {
+                    bytes.try_into().ok().map(Self::from_le_bytes)
+                }
+            }
+        )*
+    };
+}
+
+impl_fixed_codec!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128);
+
+impl From<io::Error> for DecodeError {
+    fn from(error: io::Error) -> Self {
+        Self::Io(error)
+    }
+}
+
+macro_rules! impl_binary_codec_for_number {
+    ($($number:ty),*) => {
+        $(
+            impl Encode for $number {
+                fn encode<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+                    writer.write_all(&self.to_le_bytes())
+                }
+            }
+
+            impl Decode for $number {
+                fn decode<R: Read>(reader: &mut R) -> Result<Self, DecodeError> {
+                    let mut bytes = [0; size_of::<Self>()];
+
+                    reader.read_exact(&mut bytes)?;
+
+                    Ok(Self::from_le_bytes(bytes))
+                }
+            }
+        )*
+    };
+}
+
+impl_binary_codec_for_number!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128, f32, f64);
+
+impl Encode for usize {
+    fn encode<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        encode_len(*self, writer)
+    }
+}
+
+impl Decode for usize {
+    fn decode<R: Read>(reader: &mut R) -> Result<Self, DecodeError> {
+        Self::try_from(<u64 as Decode>::decode(reader)?).map_err(|_| DecodeError::IntegerOverflow)
+    }
+}
+
+impl Encode for isize {
+    fn encode<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        let value = i64::try_from(*self).expect("isize fits in i64");
+
+        Encode::encode(&value, writer)
+    }
+}
+
+impl Decode for isize {
+    fn decode<R: Read>(reader: &mut R) -> Result<Self, DecodeError> {
+        Self::try_from(<i64 as Decode>::decode(reader)?).map_err(|_| DecodeError::IntegerOverflow)
+    }
+}
+
+impl Encode for bool {
+    fn encode<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        Encode::encode(&u8::from(*self), writer)
+    }
+}
+
+impl Decode for bool {
+    fn decode<R: Read>(reader: &mut R) -> Result<Self, DecodeError> {
+        decode_tag(reader)
+    }
+}
+
+impl Encode for String {
+    fn encode<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        encode_len(self.len(), writer)?;
+        writer.write_all(self.as_bytes())
+    }
+}
+
+impl Decode for String {
+    fn decode<R: Read>(reader: &mut R) -> Result<Self, DecodeError> {
+        Self::from_utf8(decode_bytes(reader)?).map_err(|_| DecodeError::InvalidUtf8)
+    }
+}
+
+impl Encode for Vec<u8> {
+    fn encode<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        encode_len(self.len(), writer)?;
+        writer.write_all(self)
+    }
+}
+
+impl Decode for Vec<u8> {
+    fn decode<R: Read>(reader: &mut R) -> Result<Self, DecodeError> {
+        decode_bytes(reader)
+    }
+}
+
+impl<T: Encode> Encode for Option<T> {
+    fn encode<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        match self {
+            Some(value) => {
+                true.encode(writer)?;
+                value.encode(writer)
+            }
+            None => false.encode(writer),
+        }
+    }
+}
+
+impl<T: Decode> Decode for Option<T> {
+    fn decode<R: Read>(reader: &mut R) -> Result<Self, DecodeError> {
+        if decode_tag(reader)? {
+            T::decode(reader).map(Some)
+        } else {
+            Ok(None)
+        }
+    }
+}
+
+macro_rules! impl_binary_codec_for_tuple {
+    ($($name:ident $field:ident),+) => {
+        impl<$($name: Encode),+> Encode for ($($name,)+) {
+            fn encode<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+                let ($($field,)+) = self;
+
+                $($field.encode(writer)?;)+
+
+                Ok(())
+            }
+        }
+
+        impl<$($name: Decode),+> Decode for ($($name,)+) {
+            fn decode<R: Read>(reader: &mut R) -> Result<Self, DecodeError> {
+                Ok(($($name::decode(reader)?,)+))
+            }
+        }
+    };
+}
+
+impl_binary_codec_for_tuple!(A a);
+impl_binary_codec_for_tuple!(A a, B b);
+impl_binary_codec_for_tuple!(A a, B b, C c);
+impl_binary_codec_for_tuple!(A a, B b, C c, D d);
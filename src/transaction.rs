@@ -0,0 +1,249 @@
+use std::ptr::{self, NonNull};
+
+use super::{
+    LinkedList,
+    list::ListIter,
+    node::{Link, Node},
+    node_allocator::{allocate_node, deallocate_node},
+};
+
+/// Reverts a single relink made during a transaction.
+enum Undo<T> {
+    /// The node was inserted and is unlinked and freed on rollback.
+    Unlink(NonNull<Node<T>>),
+    /// The node was removed and is linked back between its old neighbours on
+    /// rollback. It stays allocated until the transaction ends.
+    Relink {
+        node: NonNull<Node<T>>,
+        previous: Link<T>,
+        next: Link<T>,
+    },
+}
+
+/// Edits applied to a list through `LinkedList::transaction`.
+///
+/// Removed elements stay in the list's nodes until the transaction commits,
+/// so removals hand out references rather than owned elements.
+pub struct Transaction<'a, T> {
+    pub(super) list: &'a mut LinkedList<T>,
+    undo: Vec<Undo<T>>,
+    pub(super) committed: bool,
+}
+
+impl<'a, T> Transaction<'a, T> {
+    pub(super) const fn new(list: &'a mut LinkedList<T>) -> Self {
+        Self {
+            list,
+            undo: vec![],
+            committed: false,
+        }
+    }
+
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.list.len()
+    }
+
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.list.is_empty()
+    }
+
+    #[must_use]
+    pub fn front(&self) -> Option<&T> {
+        self.list.front()
+    }
+
+    #[must_use]
+    pub fn back(&self) -> Option<&T> {
+        self.list.back()
+    }
+
+    #[must_use]
+    pub const fn iter(&self) -> ListIter<'_, T> {
+        self.list.iter()
+    }
+
+    pub fn push_front(&mut self, element: T) {
+        let next = self.list.head;
+
+        self.insert_node(element, None, next);
+    }
+
+    pub fn push_back(&mut self, element: T) {
+        let previous = self.list.tail;
+
+        self.insert_node(element, previous, None);
+    }
+
+    /// Inserts `element` so that it ends up at position `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is greater than the list's length.
+    pub fn insert(&mut self, index: usize, element: T) {
+        assert!(
+            index <= self.list.size,
+            "insertion index (is {index}) should be <= len (is {})",
+            self.list.size
+        );
+
+        if index == self.list.size {
+            self.push_back(element);
+        } else {
+            let next = self.node_at(index);
+            let previous = unsafe { next.as_ref().previous };
+
+            self.insert_node(element, previous, Some(next));
+        }
+    }
+
+    pub fn pop_front(&mut self) -> Option<&T> {
+        let node = self.list.head?;
+
+        Some(self.remove_node(node))
+    }
+
+    pub fn pop_back(&mut self) -> Option<&T> {
+        let node = self.list.tail?;
+
+        Some(self.remove_node(node))
+    }
+
+    pub fn remove(&mut self, index: usize) -> Option<&T> {
+        if index >= self.list.size {
+            return None;
+        }
+
+        let node = self.node_at(index);
+
+        Some(self.remove_node(node))
+    }
+
+    /// Walks from whichever end of the list is closer to `index`.
+    fn node_at(&self, index: usize) -> NonNull<Node<T>> {
+        let mut node;
+
+        unsafe {
+            if index < self.list.size / 2 {
+                node = self.list.head.unwrap_unchecked();
+
+                for _ in 0..index {
+                    node = node.as_ref().next.unwrap_unchecked();
+                }
+            } else {
+                node = self.list.tail.unwrap_unchecked();
+
+                for _ in index + 1..self.list.size {
+                    node = node.as_ref().previous.unwrap_unchecked();
+                }
+            }
+        }
+
+        node
+    }
+
+    fn insert_node(&mut self, element: T, previous: Link<T>, next: Link<T>) {
+        let node = allocate_node(Node::new(element));
+
+        self.link(node, previous, next);
+        self.undo.push(Undo::Unlink(node));
+    }
+
+    fn remove_node(&mut self, node: NonNull<Node<T>>) -> &T {
+        let (previous, next) = self.unlink(node);
+
+        self.undo.push(Undo::Relink {
+            node,
+            previous,
+            next,
+        });
+
+        unsafe { &node.as_ref().element }
+    }
+
+    const fn link(&mut self, mut node: NonNull<Node<T>>, previous: Link<T>, next: Link<T>) {
+        unsafe {
+            node.as_mut().previous = previous;
+            node.as_mut().next = next;
+
+            match previous {
+                Some(mut previous) => previous.as_mut().next = Some(node),
+                None => self.list.head = Some(node),
+            }
+
+            match next {
+                Some(mut next) => next.as_mut().previous = Some(node),
+                None => self.list.tail = Some(node),
+            }
+        }
+
+        self.list.size += 1;
+    }
+
+    const fn unlink(&mut self, node: NonNull<Node<T>>) -> (Link<T>, Link<T>) {
+        let (previous, next) = unsafe { (node.as_ref().previous, node.as_ref().next) };
+
+        unsafe {
+            match previous {
+                Some(mut previous) => previous.as_mut().next = next,
+                None => self.list.head = next,
+            }
+
+            match next {
+                Some(mut next) => next.as_mut().previous = previous,
+                None => self.list.tail = previous,
+            }
+        }
+
+        self.list.size -= 1;
+
+        (previous, next)
+    }
+
+    /// Frees the nodes removed during the transaction.
+    pub(super) fn release_removed(&mut self) {
+        let nodes = self.undo.drain(..).filter_map(|entry| match entry {
+            Undo::Relink { node, .. } => Some(node),
+            Undo::Unlink(_) => None,
+        });
+
+        drop(take_elements(nodes));
+    }
+
+    /// Reverts every relink in reverse order, then frees the inserted nodes.
+    pub(super) fn rollback(&mut self) {
+        let mut inserted = vec![];
+
+        while let Some(entry) = self.undo.pop() {
+            match entry {
+                Undo::Unlink(node) => {
+                    self.unlink(node);
+                    inserted.push(node);
+                }
+                Undo::Relink {
+                    node,
+                    previous,
+                    next,
+                } => self.link(node, previous, next),
+            }
+        }
+
+        drop(take_elements(inserted));
+    }
+}
+
+/// Moves the elements out of detached nodes and frees the nodes. The elements
+/// are dropped by the caller, so a panicking destructor cannot leak nodes.
+fn take_elements<T>(nodes: impl IntoIterator<Item = NonNull<Node<T>>>) -> Vec<T> {
+    nodes
+        .into_iter()
+        .map(|node| unsafe {
+            let element = ptr::read(&raw const node.as_ref().element);
+
+            deallocate_node(node);
+
+            element
+        })
+        .collect()
+}
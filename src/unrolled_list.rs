@@ -0,0 +1,416 @@
+use std::{
+    marker::PhantomData,
+    ptr::{self, NonNull},
+};
+
+use super::{
+    node::{UnrolledLink, UnrolledNode},
+    node_allocator::{allocate_node, deallocate_node},
+};
+
+pub struct UnrolledIter<'a, T, const N: usize> {
+    pub(super) current: UnrolledLink<T, N>,
+    pub(super) offset: usize,
+    _marker: PhantomData<&'a T>,
+}
+
+pub struct UnrolledIterMut<'a, T, const N: usize> {
+    pub(super) current: UnrolledLink<T, N>,
+    pub(super) offset: usize,
+    _marker: PhantomData<&'a mut T>,
+}
+
+pub struct UnrolledIntoIter<T, const N: usize> {
+    pub(super) list: UnrolledLinkedList<T, N>,
+}
+
+/// A doubly linked list whose nodes store up to `N` elements inline.
+///
+/// Nodes are never left empty: a node is freed as soon as its last element
+/// is removed, and neighbouring nodes are merged when their elements fit
+/// into a single node.
+#[derive(Debug)]
+pub struct UnrolledLinkedList<T, const N: usize> {
+    pub(super) head: UnrolledLink<T, N>,
+    pub(super) tail: UnrolledLink<T, N>,
+    pub(super) size: usize,
+}
+
+impl<T, const N: usize> UnrolledLinkedList<T, N> {
+    #[must_use]
+    pub const fn new() -> Self {
+        const { assert!(N > 0, "unrolled nodes must hold at least one element") };
+
+        Self {
+            head: None,
+            tail: None,
+            size: 0,
+        }
+    }
+
+    pub fn push_front(&mut self, element: T) {
+        let mut head = match self.head {
+            Some(head) if unsafe { !head.as_ref().is_full() } => head,
+            _ => self.push_node_front(),
+        };
+
+        unsafe { head.as_mut().insert(0, element) };
+
+        self.size += 1;
+    }
+
+    pub fn push_back(&mut self, element: T) {
+        let mut tail = match self.tail {
+            Some(tail) if unsafe { !tail.as_ref().is_full() } => tail,
+            _ => self.push_node_back(),
+        };
+
+        unsafe {
+            let offset = tail.as_ref().len;
+
+            tail.as_mut().insert(offset, element);
+        }
+
+        self.size += 1;
+    }
+
+    pub fn pop_front(&mut self) -> Option<T> {
+        let mut head = self.head?;
+
+        let popped_element = unsafe { head.as_mut().remove(0) };
+
+        if unsafe { head.as_ref().len } == 0 {
+            self.remove_node(head);
+        }
+
+        self.size -= 1;
+
+        Some(popped_element)
+    }
+
+    pub fn pop_back(&mut self) -> Option<T> {
+        let mut tail = self.tail?;
+
+        let popped_element = unsafe {
+            let offset = tail.as_ref().len - 1;
+
+            tail.as_mut().remove(offset)
+        };
+
+        if unsafe { tail.as_ref().len } == 0 {
+            self.remove_node(tail);
+        }
+
+        self.size -= 1;
+
+        Some(popped_element)
+    }
+
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.size == 0
+    }
+
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.size
+    }
+
+    #[must_use]
+    pub fn front(&self) -> Option<&T> {
+        self.head.map(|head| unsafe { &*head.as_ref().as_ptr() })
+    }
+
+    pub fn front_mut(&mut self) -> Option<&mut T> {
+        self.head
+            .map(|mut head| unsafe { &mut *head.as_mut().as_mut_ptr() })
+    }
+
+    #[must_use]
+    pub fn back(&self) -> Option<&T> {
+        self.tail.map(|tail| unsafe {
+            let tail_ref = tail.as_ref();
+
+            &*tail_ref.as_ptr().add(tail_ref.len - 1)
+        })
+    }
+
+    pub fn back_mut(&mut self) -> Option<&mut T> {
+        self.tail.map(|mut tail| unsafe {
+            let tail_ref = tail.as_mut();
+
+            &mut *tail_ref.as_mut_ptr().add(tail_ref.len - 1)
+        })
+    }
+
+    #[must_use]
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.locate(index)
+            .map(|(node, offset)| unsafe { &*node.as_ref().as_ptr().add(offset) })
+    }
+
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        self.locate(index)
+            .map(|(mut node, offset)| unsafe { &mut *node.as_mut().as_mut_ptr().add(offset) })
+    }
+
+    /// Inserts `element` at `index`, shifting all elements after it.
+    ///
+    /// A full node is split in half before the element is inserted.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is greater than the list's length.
+    pub fn insert(&mut self, index: usize, element: T) {
+        assert!(
+            index <= self.size,
+            "insertion index (is {index}) should be <= len (is {})",
+            self.size
+        );
+
+        let Some((mut node, mut offset)) = self.locate(index) else {
+            self.push_back(element);
+
+            return;
+        };
+
+        if unsafe { node.as_ref().is_full() } {
+            let new_node = self.split_node(node);
+            let kept = unsafe { node.as_ref().len };
+
+            if offset > kept {
+                node = new_node;
+                offset -= kept;
+            }
+        }
+
+        unsafe { node.as_mut().insert(offset, element) };
+
+        self.size += 1;
+    }
+
+    /// Removes and returns the element at `index`, or `None` if it is out of
+    /// bounds.
+    ///
+    /// The node the element was removed from is merged with a neighbour when
+    /// both fit into a single node.
+    pub fn remove(&mut self, index: usize) -> Option<T> {
+        let (mut node, offset) = self.locate(index)?;
+
+        let removed_element = unsafe { node.as_mut().remove(offset) };
+
+        self.size -= 1;
+
+        if unsafe { node.as_ref().len } == 0 {
+            self.remove_node(node);
+        } else {
+            let next_node = unsafe { node.as_ref().next };
+            let previous_node = unsafe { node.as_ref().previous };
+
+            if !next_node.is_some_and(|next| self.merge_nodes(node, next))
+                && let Some(previous) = previous_node
+            {
+                self.merge_nodes(previous, node);
+            }
+        }
+
+        Some(removed_element)
+    }
+
+    pub fn contains(&self, element: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        self.iter().any(|current| current == element)
+    }
+
+    pub fn clear(&mut self) {
+        while self.pop_front().is_some() {}
+    }
+
+    #[must_use]
+    pub const fn iter(&self) -> UnrolledIter<'_, T, N> {
+        UnrolledIter {
+            current: self.head,
+            offset: 0,
+            _marker: PhantomData,
+        }
+    }
+
+    pub const fn iter_mut(&mut self) -> UnrolledIterMut<'_, T, N> {
+        UnrolledIterMut {
+            current: self.head,
+            offset: 0,
+            _marker: PhantomData,
+        }
+    }
+
+    const fn locate(&self, index: usize) -> Option<(NonNull<UnrolledNode<T, N>>, usize)> {
+        if index >= self.size {
+            return None;
+        }
+
+        if index < self.size / 2 {
+            let mut remaining = index;
+            let mut current_node = self.head;
+
+            while let Some(node) = current_node {
+                let node_ref = unsafe { node.as_ref() };
+
+                if remaining < node_ref.len {
+                    return Some((node, remaining));
+                }
+
+                remaining -= node_ref.len;
+                current_node = node_ref.next;
+            }
+        } else {
+            let mut remaining = self.size - 1 - index;
+            let mut current_node = self.tail;
+
+            while let Some(node) = current_node {
+                let node_ref = unsafe { node.as_ref() };
+
+                if remaining < node_ref.len {
+                    return Some((node, node_ref.len - 1 - remaining));
+                }
+
+                remaining -= node_ref.len;
+                current_node = node_ref.previous;
+            }
+        }
+
+        None
+    }
+
+    fn push_node_front(&mut self) -> NonNull<UnrolledNode<T, N>> {
+        let mut new_node = UnrolledNode::new();
+
+        new_node.next = self.head;
+
+        let new_node_ptr = allocate_node(new_node);
+
+        if let Some(mut old_head) = self.head {
+            unsafe { old_head.as_mut().previous = Some(new_node_ptr) };
+        } else {
+            self.tail = Some(new_node_ptr);
+        }
+
+        self.head = Some(new_node_ptr);
+
+        new_node_ptr
+    }
+
+    fn push_node_back(&mut self) -> NonNull<UnrolledNode<T, N>> {
+        let mut new_node = UnrolledNode::new();
+
+        new_node.previous = self.tail;
+
+        let new_node_ptr = allocate_node(new_node);
+
+        if let Some(mut old_tail) = self.tail {
+            unsafe { old_tail.as_mut().next = Some(new_node_ptr) };
+        } else {
+            self.head = Some(new_node_ptr);
+        }
+
+        self.tail = Some(new_node_ptr);
+
+        new_node_ptr
+    }
+
+    fn insert_node_after(
+        &mut self,
+        mut node: NonNull<UnrolledNode<T, N>>,
+    ) -> NonNull<UnrolledNode<T, N>> {
+        let mut new_node = UnrolledNode::new();
+
+        new_node.previous = Some(node);
+        new_node.next = unsafe { node.as_ref().next };
+
+        let new_node_ptr = allocate_node(new_node);
+
+        if let Some(mut next_node) = unsafe { node.as_ref().next } {
+            unsafe { next_node.as_mut().previous = Some(new_node_ptr) };
+        } else {
+            self.tail = Some(new_node_ptr);
+        }
+
+        unsafe { node.as_mut().next = Some(new_node_ptr) };
+
+        new_node_ptr
+    }
+
+    fn split_node(&mut self, mut node: NonNull<UnrolledNode<T, N>>) -> NonNull<UnrolledNode<T, N>> {
+        let mut new_node = self.insert_node_after(node);
+
+        unsafe {
+            let node_ref = node.as_mut();
+            let new_node_ref = new_node.as_mut();
+            let kept = node_ref.len / 2;
+            let moved = node_ref.len - kept;
+
+            ptr::copy_nonoverlapping(
+                node_ref.as_ptr().add(kept),
+                new_node_ref.as_mut_ptr(),
+                moved,
+            );
+
+            node_ref.len = kept;
+            new_node_ref.len = moved;
+        }
+
+        new_node
+    }
+
+    fn merge_nodes(
+        &mut self,
+        mut left: NonNull<UnrolledNode<T, N>>,
+        mut right: NonNull<UnrolledNode<T, N>>,
+    ) -> bool {
+        unsafe {
+            let left_ref = left.as_mut();
+            let right_ref = right.as_mut();
+
+            if left_ref.len + right_ref.len > N {
+                return false;
+            }
+
+            ptr::copy_nonoverlapping(
+                right_ref.as_ptr(),
+                left_ref.as_mut_ptr().add(left_ref.len),
+                right_ref.len,
+            );
+
+            left_ref.len += right_ref.len;
+            right_ref.len = 0;
+        }
+
+        self.remove_node(right);
+
+        true
+    }
+
+    fn remove_node(&mut self, node: NonNull<UnrolledNode<T, N>>) {
+        unsafe {
+            debug_assert_eq!(node.as_ref().len, 0);
+
+            let previous_node = node.as_ref().previous;
+            let next_node = node.as_ref().next;
+
+            if let Some(mut node) = previous_node {
+                node.as_mut().next = next_node;
+            } else {
+                self.head = next_node;
+            }
+
+            if let Some(mut node) = next_node {
+                node.as_mut().previous = previous_node;
+            } else {
+                self.tail = previous_node;
+            }
+
+            deallocate_node(node);
+        }
+    }
+}
@@ -0,0 +1,252 @@
+use std::{marker::PhantomData, mem, ptr};
+
+use super::{
+    node::{XorLink, XorNode, address},
+    node_allocator::{allocate_node, deallocate_node},
+};
+
+pub struct XorIter<'a, T> {
+    pub(super) front: XorLink<T>,
+    pub(super) front_previous: XorLink<T>,
+    pub(super) back: XorLink<T>,
+    pub(super) back_next: XorLink<T>,
+    pub(super) remaining: usize,
+    _marker: PhantomData<&'a T>,
+}
+
+pub struct XorIterMut<'a, T> {
+    pub(super) front: XorLink<T>,
+    pub(super) front_previous: XorLink<T>,
+    pub(super) back: XorLink<T>,
+    pub(super) back_next: XorLink<T>,
+    pub(super) remaining: usize,
+    _marker: PhantomData<&'a mut T>,
+}
+
+pub struct XorIntoIter<T> {
+    pub(super) list: XorLinkedList<T>,
+}
+
+/// A doubly linked list whose nodes store a single `previous ^ next` link.
+///
+/// Traversal starts from either end and recovers each neighbour from the one
+/// it came from, which also makes `reverse` a constant time swap.
+#[derive(Debug)]
+pub struct XorLinkedList<T> {
+    pub(super) head: XorLink<T>,
+    pub(super) tail: XorLink<T>,
+    pub(super) size: usize,
+}
+
+impl<T> XorLinkedList<T> {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            head: None,
+            tail: None,
+            size: 0,
+        }
+    }
+
+    pub fn push_front(&mut self, element: T) {
+        let mut new_node = XorNode::new(element);
+
+        new_node.link = address(self.head);
+
+        let new_node_ptr = allocate_node(new_node);
+
+        if let Some(mut old_head) = self.head {
+            unsafe { old_head.as_mut().link ^= address(Some(new_node_ptr)) };
+        } else {
+            self.tail = Some(new_node_ptr);
+        }
+
+        self.head = Some(new_node_ptr);
+        self.size += 1;
+    }
+
+    pub fn push_back(&mut self, element: T) {
+        let mut new_node = XorNode::new(element);
+
+        new_node.link = address(self.tail);
+
+        let new_node_ptr = allocate_node(new_node);
+
+        if let Some(mut old_tail) = self.tail {
+            unsafe { old_tail.as_mut().link ^= address(Some(new_node_ptr)) };
+        } else {
+            self.head = Some(new_node_ptr);
+        }
+
+        self.tail = Some(new_node_ptr);
+        self.size += 1;
+    }
+
+    pub fn pop_front(&mut self) -> Option<T> {
+        let old_head = self.head?;
+
+        self.head = unsafe { old_head.as_ref().other(None) };
+
+        if let Some(mut new_head) = self.head {
+            unsafe { new_head.as_mut().link ^= address(Some(old_head)) };
+        } else {
+            self.tail = None;
+        }
+
+        let popped_element = unsafe { ptr::read(&raw const old_head.as_ref().element) };
+
+        unsafe { deallocate_node(old_head) };
+
+        self.size -= 1;
+
+        Some(popped_element)
+    }
+
+    pub fn pop_back(&mut self) -> Option<T> {
+        let old_tail = self.tail?;
+
+        self.tail = unsafe { old_tail.as_ref().other(None) };
+
+        if let Some(mut new_tail) = self.tail {
+            unsafe { new_tail.as_mut().link ^= address(Some(old_tail)) };
+        } else {
+            self.head = None;
+        }
+
+        let popped_element = unsafe { ptr::read(&raw const old_tail.as_ref().element) };
+
+        unsafe { deallocate_node(old_tail) };
+
+        self.size -= 1;
+
+        Some(popped_element)
+    }
+
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.size == 0
+    }
+
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.size
+    }
+
+    #[must_use]
+    pub fn front(&self) -> Option<&T> {
+        self.head.map(|head| unsafe { &head.as_ref().element })
+    }
+
+    pub fn front_mut(&mut self) -> Option<&mut T> {
+        self.head
+            .map(|mut head| unsafe { &mut head.as_mut().element })
+    }
+
+    #[must_use]
+    pub fn back(&self) -> Option<&T> {
+        self.tail.map(|tail| unsafe { &tail.as_ref().element })
+    }
+
+    pub fn back_mut(&mut self) -> Option<&mut T> {
+        self.tail
+            .map(|mut tail| unsafe { &mut tail.as_mut().element })
+    }
+
+    pub fn contains(&self, element: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        self.iter().any(|current| current == element)
+    }
+
+    pub fn clear(&mut self) {
+        while self.pop_front().is_some() {}
+    }
+
+    pub const fn reverse(&mut self) {
+        mem::swap(&mut self.head, &mut self.tail);
+    }
+
+    #[must_use]
+    pub fn split(self) -> (Self, Self)
+    where
+        T: Clone,
+    {
+        let mid = self.len().div_ceil(2);
+        let mut first_list = Self::new();
+        let mut second_list = Self::new();
+
+        for (index, element) in self.iter().enumerate() {
+            if index < mid {
+                first_list.push_back(element.clone());
+            } else {
+                second_list.push_back(element.clone());
+            }
+        }
+
+        (first_list, second_list)
+    }
+
+    pub fn retain<F>(&mut self, predicate: F)
+    where
+        F: Fn(&T) -> bool,
+    {
+        let mut previous_node: XorLink<T> = None;
+        let mut current_node = self.head;
+
+        while let Some(node) = current_node {
+            let next_node = unsafe { node.as_ref().other(previous_node) };
+
+            if predicate(unsafe { &node.as_ref().element }) {
+                previous_node = current_node;
+            } else {
+                unsafe {
+                    if let Some(mut previous) = previous_node {
+                        previous.as_mut().link ^= address(current_node) ^ address(next_node);
+                    } else {
+                        self.head = next_node;
+                    }
+
+                    if let Some(mut next) = next_node {
+                        next.as_mut().link ^= address(current_node) ^ address(previous_node);
+                    } else {
+                        self.tail = previous_node;
+                    }
+
+                    let removed_element = ptr::read(&raw const node.as_ref().element);
+
+                    deallocate_node(node);
+
+                    self.size -= 1;
+
+                    drop(removed_element);
+                }
+            }
+
+            current_node = next_node;
+        }
+    }
+
+    #[must_use]
+    pub const fn iter(&self) -> XorIter<'_, T> {
+        XorIter {
+            front: self.head,
+            front_previous: None,
+            back: self.tail,
+            back_next: None,
+            remaining: self.size,
+            _marker: PhantomData,
+        }
+    }
+
+    pub const fn iter_mut(&mut self) -> XorIterMut<'_, T> {
+        XorIterMut {
+            front: self.head,
+            front_previous: None,
+            back: self.tail,
+            back_next: None,
+            remaining: self.size,
+            _marker: PhantomData,
+        }
+    }
+}